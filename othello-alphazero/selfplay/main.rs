@@ -13,22 +13,22 @@ use indicatif::MultiProgress;
 use indicatif::ProgressBar;
 use ndarray::{Array1, Array3, Axis};
 use ndarray_npy::write_npy;
-use othello::{players::AlphaZeroPlayer, Board, Position, StoneCount, SIZE, UPPER_LEFT};
+use othello::{players::AlphaZeroPlayer, Board, Position, SIZE, UPPER_LEFT};
 use tokio::spawn;
 use tokio::task::spawn_blocking;
 
 fn create_board_array(board: &Board) -> Array3<u8> {
     let mut board_array = Array3::zeros((2, SIZE, SIZE));
-    let (black_idx, white_idx) = if board.turn == Stone::Black {
+    let (black_idx, white_idx) = if board.turn() == Stone::Black {
         (0, 1)
     } else {
         (1, 0)
     };
     for i in 0..SIZE * SIZE {
         let pos = UPPER_LEFT >> i;
-        if board.black & pos != 0 {
+        if board.black() & pos != 0 {
             board_array[[black_idx, i / SIZE, i % SIZE]] = 1;
-        } else if board.white & pos != 0 {
+        } else if board.white() & pos != 0 {
             board_array[[white_idx, i / SIZE, i % SIZE]] = 1;
         }
     }
@@ -41,7 +41,7 @@ async fn simulate(
     mcts_simulation: usize
 ) -> Result<(Vec<Array3<u8>>, Vec<Array1<f32>>, Vec<i32>)> {
     let mut rng = SmallRng::from_entropy();
-    let mut player = AlphaZeroPlayer::new_from_model_path("./models/model.onnx", mcts_simulation);
+    let mut player = AlphaZeroPlayer::new_from_model_path("./models/model.onnx", mcts_simulation)?;
     // let mut player = AlphaZeroPlayer::new_from_model_path("/Users/near129/GoggleDrive/tmp/regnet.onnx", mcts_simulation);
     let mut states = vec![];
     let mut policy = vec![];
@@ -51,7 +51,7 @@ async fn simulate(
         let mut tmp_values = vec![];
         let mut i = 0;
         while !board.finished() {
-            tmp_values.push(if board.turn == Stone::Black { 1 } else { -1 });
+            tmp_values.push(if board.turn() == Stone::Black { 1 } else { -1 });
             states.push(create_board_array(&board));
             let ret = player.mcts.search(board)?;
             let idx = if i <= 30 {
@@ -78,12 +78,9 @@ async fn simulate(
             board.put(pos)?;
             i += 1;
         }
-        let StoneCount { black, white } = board.count_stone();
-        let res = match black.cmp(&white) {
-            Ordering::Equal => 0,
-            Ordering::Greater => 1,
-            Ordering::Less => -1,
-        };
+        let res = board
+            .outcome()
+            .map_or(0, |outcome| outcome.result_for(Stone::Black) as i32);
         values.extend(tmp_values.iter().map(|x| x * res));
         player.mcts.clear_cache();
         pb.inc(1);
@@ -6,14 +6,14 @@ use indicatif::{MultiProgress, ProgressBar};
 #[allow(unused_imports)]
 use othello::{
     players::{AlphaZeroPlayer, GreedyPlayer, MCTSPlayer, Player, RandomPlayer},
-    Board, Stone, StoneCount,
+    Board, Stone,
 };
 use tokio::spawn;
 use tokio::task::spawn_blocking;
 
 const NUM_SIMULATION: usize = 50;
 async fn battle(idx: usize, model_path: String, pb: ProgressBar) -> Result<usize> {
-    let mut player1 = AlphaZeroPlayer::new_from_model_path(&model_path, NUM_SIMULATION);
+    let mut player1 = AlphaZeroPlayer::new_from_model_path(&model_path, NUM_SIMULATION)?;
     // let mut player2 = AlphaZeroPlayer::new(NUM_SIMULATION);
     let mut player2 = GreedyPlayer::default();
     let player1_stone = if idx % 2 == 0 {
@@ -23,17 +23,17 @@ async fn battle(idx: usize, model_path: String, pb: ProgressBar) -> Result<usize
     };
     let mut board = Board::new();
     while !board.finished() {
-        let pos = if board.turn == player1_stone {
+        let mv = if board.turn() == player1_stone {
             player1.find_move(&board)
         } else {
             player2.find_move(&board)
         }?;
-        board.put(pos)?;
+        board.apply(mv)?;
     }
-    let StoneCount { black, white } = board.count_stone();
     pb.inc(1);
-    if (player1_stone == Stone::Black && black >= white)
-        || (player1_stone == Stone::White && white >= black)
+    if board
+        .outcome()
+        .is_some_and(|outcome| outcome.result_for(player1_stone) >= 0)
     {
         Ok(1)
     } else {
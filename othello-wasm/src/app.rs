@@ -2,7 +2,7 @@ use std::time::Duration;
 
 use othello::{
     players::{AlphaZeroPlayer, Player},
-    Board, Position, Stone, StoneCount,
+    Board, Move, Position, Stone, StoneCount,
 };
 use yew::{
     prelude::*,
@@ -38,7 +38,7 @@ impl Component for App {
     fn create(_: Self::Properties, link: ComponentLink<Self>) -> Self {
         App {
             board: Board::default(),
-            ai: AlphaZeroPlayer::new(300),
+            ai: AlphaZeroPlayer::new(300).expect("failed to load the model"),
             link,
             state: State::GameSetting,
             player_stone: Stone::Black,
@@ -51,7 +51,7 @@ impl Component for App {
                 let pos = Position::new(x, y);
                 if let Err(message) = self.board.put(pos) {
                     log::error!("{}", message);
-                } else if self.board.turn != self.player_stone {
+                } else if self.board.turn() != self.player_stone {
                     let handle = TimeoutService::spawn(
                         Duration::from_millis(1),
                         self.link.callback(|_| Msg::PutComputer),
@@ -63,8 +63,12 @@ impl Component for App {
             }
             Msg::PutComputer => {
                 match self.ai.find_move(&self.board) {
-                    Ok(pos) => {
-                        if let Err(message) = self.board.put(pos) {
+                    Ok(mv) => {
+                        let ret = match mv {
+                            Move::Play(pos) => self.board.put(pos),
+                            Move::Pass => self.board.apply(mv),
+                        };
+                        if let Err(message) = ret {
                             log::error!("ai error: {}", message);
                         }
                         self.state = State::PlayerTurn;
@@ -73,7 +77,7 @@ impl Component for App {
                         log::error!("{}", message);
                     }
                 };
-                if self.board.turn != self.player_stone {
+                if self.board.turn() != self.player_stone {
                     let handle = TimeoutService::spawn(
                         Duration::from_millis(1),
                         self.link.callback(|_| Msg::PutComputer),
@@ -86,7 +90,7 @@ impl Component for App {
             }
             Msg::SelectPlayer(stone) => {
                 self.player_stone = stone;
-                if self.board.turn == self.player_stone {
+                if self.board.turn() == self.player_stone {
                     self.state = State::PlayerTurn;
                 } else {
                     self.link.send_message(Msg::PutComputer);
@@ -106,7 +110,7 @@ impl Component for App {
 
     fn view(&self) -> Html {
         let StoneCount { black, white } = self.board.count_stone();
-        let score_black = if self.board.turn == Stone::Black {
+        let score_black = if self.board.turn() == Stone::Black {
             html! {
                 <div class="points outlined">
                     <div class="demopiece black"></div><span>{black}</span>
@@ -119,7 +123,7 @@ impl Component for App {
                 </div>
             }
         };
-        let score_white = if self.board.turn == Stone::White {
+        let score_white = if self.board.turn() == Stone::White {
             html! {
                 <div class="points outlined">
                     <div class="demopiece white"></div><span>{white}</span>
@@ -162,7 +166,7 @@ impl Component for App {
                                     }
                                 }
                                 (None, true) => {
-                                    if self.board.turn == Stone::Black {
+                                    if self.board.turn() == Stone::Black {
                                         html! {
                                             <div class="avail avail-black"></div>
                                         }
@@ -189,10 +193,11 @@ impl Component for App {
                             <div class="statecard">
                                 <span class="result">
                                     {
-                                        if black == white { "Drow" } else if (
-                                            self.player_stone == Stone::Black && black > white)
-                                            || (self.player_stone == Stone::White && black < white)
-                                            { "Win" } else { "Lose" }
+                                        match self.board.outcome().map(|o| o.result_for(self.player_stone)) {
+                                            Some(1) => "Win",
+                                            Some(-1) => "Lose",
+                                            _ => "Draw",
+                                        }
                                     }
                                 </span>
                                 <span class="again" onclick=self.link.callback(|_| Msg::Restart)>{ "Play again" }</span>
@@ -0,0 +1,69 @@
+// Times every move generator on the positions of random games, the work an
+// MCTS playout does: all legal moves, then the flips of each.
+//
+//     cargo bench --bench move_generation
+
+use std::time::Instant;
+
+use othello::othello_logic::backends;
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+fn positions() -> Vec<(u64, u64)> {
+    let mut rng = SmallRng::seed_from_u64(0);
+    let mut positions = vec![];
+    for _ in 0..2000 {
+        let (mut player, mut opponent) = (0x0000000810000000, 0x0000001008000000);
+        let mut passed = false;
+        loop {
+            positions.push((player, opponent));
+            let moves = backends::legal_move_reference(player, opponent);
+            if moves == 0 {
+                if passed {
+                    break;
+                }
+                passed = true;
+            } else {
+                passed = false;
+                let mut rest = moves;
+                for _ in 0..rng.gen_range(0..moves.count_ones()) {
+                    rest &= rest - 1;
+                }
+                let pos = rest & rest.wrapping_neg();
+                let flipped = backends::reverse_reference(player, opponent, pos);
+                player ^= pos | flipped;
+                opponent ^= flipped;
+            }
+            std::mem::swap(&mut player, &mut opponent);
+        }
+    }
+    positions
+}
+
+fn main() {
+    let positions = positions();
+    let mut reference = None;
+    for (name, legal_move, reverse) in backends::available() {
+        let start = Instant::now();
+        let mut checksum = 0u64;
+        for _ in 0..20 {
+            for &(player, opponent) in &positions {
+                let mut moves = legal_move(player, opponent);
+                checksum = checksum.wrapping_add(moves);
+                while moves != 0 {
+                    let pos = moves & moves.wrapping_neg();
+                    moves ^= pos;
+                    checksum = checksum.wrapping_add(reverse(player, opponent, pos));
+                }
+            }
+        }
+        let elapsed = start.elapsed().as_secs_f64();
+        let reference = *reference.get_or_insert(elapsed);
+        println!(
+            "{:<10} {:>8.1} ns/position {:>6.2}x  ({:x})",
+            name,
+            elapsed * 1e9 / (20 * positions.len()) as f64,
+            reference / elapsed,
+            checksum
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use std::{env, error::Error, time::Instant};
+
+use othello::{
+    perft::{perft, perft_symmetric, PERFT_COUNTS},
+    Board,
+};
+
+const USAGE: &str = "usage: perft <depth> [position string] [--symmetric] [--verify]";
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut depth = None;
+    let mut board = Board::new();
+    let mut symmetric = false;
+    let mut verify = false;
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--symmetric" => symmetric = true,
+            "--verify" => verify = true,
+            _ if depth.is_none() => depth = Some(arg.parse::<usize>()?),
+            _ => board = arg.parse()?,
+        }
+    }
+    let depth = match depth {
+        Some(depth) => depth,
+        None => return Err(USAGE.into()),
+    };
+    if verify && board != Board::new() {
+        return Err("--verify only has known counts for the initial position".into());
+    }
+    println!("{}", board);
+    for d in 1..=depth {
+        let start = Instant::now();
+        let count = if symmetric {
+            perft_symmetric(&board, d)
+        } else {
+            perft(&board, d)
+        };
+        let secs = start.elapsed().as_secs_f64();
+        println!(
+            "depth {:2} {:16} {:10.3}s {:12.0} leaves/s",
+            d,
+            count,
+            secs,
+            count as f64 / secs
+        );
+        if verify {
+            match PERFT_COUNTS.get(d) {
+                Some(&expected) if expected != count => {
+                    return Err(format!("depth {}: expected {}, got {}", d, expected, count).into());
+                }
+                Some(_) => {}
+                None => println!("no known count for depth {}", d),
+            }
+        }
+    }
+    Ok(())
+}
@@ -1,12 +1,19 @@
-use std::fmt::{self, Debug, Display};
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::{
+    fmt::{self, Debug, Display},
+    str::FromStr,
+};
 
-use crate::othello_logic::{legal_move, put};
-use anyhow::Result;
+use crate::othello_logic::{Bitboard, Geometry, Size};
+use rand::Rng;
 use thiserror::Error;
 
 pub const SIZE: usize = 8;
 pub const UPPER_LEFT: u64 = 0x8000000000000000;
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum Stone {
     White,
     Black,
@@ -24,35 +31,59 @@ pub struct StoneCount {
     pub black: usize,
     pub white: usize,
 }
+/// One square of an N x N board, as a single set bit.
 #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
-pub struct Position(pub u64);
+pub struct Position<const N: usize = SIZE>(pub <Size<N> as Geometry>::Bits)
+where
+    Size<N>: Geometry;
 impl Position {
-    pub fn new(x: usize, y: usize) -> Self {
+    pub const fn new(x: usize, y: usize) -> Self {
         Position(UPPER_LEFT >> (y * SIZE + x))
     }
+}
+impl<const N: usize> Position<N>
+where
+    Size<N>: Geometry,
+{
     pub fn to_idx(&self) -> usize {
-        for i in 0..SIZE * SIZE {
-            let pos = UPPER_LEFT >> i;
-            if self.0 & pos != 0 {
-                return i;
-            }
-        }
-        unreachable!()
+        debug_assert_eq!(self.0.count_ones(), 1);
+        // the bits above the board are always clear
+        (self.0.leading_zeros() - Size::<N>::FULL.leading_zeros()) as usize
     }
 }
-impl Display for Position {
+impl<const N: usize> Display for Position<N>
+where
+    Size<N>: Geometry,
+{
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for i in 0..SIZE * SIZE {
-            let pos = UPPER_LEFT >> i;
-            if self.0 & pos != 0 {
-                return writeln!(f, "({}, {})", i / SIZE, i % SIZE);
-            }
+        let i = self.to_idx();
+        write!(f, "{}{}", (b'a' + (i % N) as u8) as char, i / N + 1)
+    }
+}
+impl<const N: usize> FromStr for Position<N>
+where
+    Size<N>: Geometry,
+{
+    type Err = BoardError;
+    fn from_str(s: &str) -> Result<Self, BoardError> {
+        let b = s.as_bytes();
+        // rows past 9 take two digits
+        if !(2..=3).contains(&b.len()) || b[1] == b'0' || !b[1..].iter().all(u8::is_ascii_digit) {
+            return Err(BoardError::InvalidNotation);
         }
-        unreachable!()
+        let x = b[0].to_ascii_lowercase().wrapping_sub(b'a') as usize;
+        let y = s[1..].parse::<usize>().unwrap() - 1;
+        if x >= N || y >= N {
+            return Err(BoardError::InvalidNotation);
+        }
+        Ok(Position(Size::<N>::square(x, y)))
     }
 }
-impl From<(usize, usize)> for Position {
-    fn from(xy: (usize, usize)) -> Position {
+impl<const N: usize> From<(usize, usize)> for Position<N>
+where
+    Size<N>: Geometry,
+{
+    fn from(xy: (usize, usize)) -> Position<N> {
         // for i in 0..SIZE * SIZE {
         //     let pos = UPPER_LEFT >> i;
         //     if p.0 & pos != 0 {
@@ -60,85 +91,264 @@ impl From<(usize, usize)> for Position {
         //     }
         // }
         // unreachable!()
-        Position(UPPER_LEFT >> (xy.1 * SIZE + xy.0))
+        Position(Size::<N>::square(xy.0, xy.1))
+    }
+}
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum Move<const N: usize = SIZE>
+where
+    Size<N>: Geometry,
+{
+    Play(Position<N>),
+    Pass,
+}
+impl<const N: usize> Display for Move<N>
+where
+    Size<N>: Geometry,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Move::Play(pos) => write!(f, "{}", pos),
+            Move::Pass => write!(f, "pa"),
+        }
     }
 }
-pub struct Positions(pub u64);
-impl Positions {
+impl<const N: usize> FromStr for Move<N>
+where
+    Size<N>: Geometry,
+{
+    type Err = BoardError;
+    fn from_str(s: &str) -> Result<Self, BoardError> {
+        if ["pa", "ps", "--"].iter().any(|p| s.eq_ignore_ascii_case(p)) {
+            Ok(Move::Pass)
+        } else {
+            s.parse().map(Move::Play)
+        }
+    }
+}
+impl<const N: usize> From<Position<N>> for Move<N>
+where
+    Size<N>: Geometry,
+{
+    fn from(pos: Position<N>) -> Move<N> {
+        Move::Play(pos)
+    }
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Positions<const N: usize = SIZE>(pub <Size<N> as Geometry>::Bits)
+where
+    Size<N>: Geometry;
+impl<const N: usize> Positions<N>
+where
+    Size<N>: Geometry,
+{
     pub fn count(&self) -> usize {
         self.0.count_ones() as usize
     }
-    pub fn to_map(&self) -> [[bool; SIZE]; SIZE] {
-        let mut m = [[false; SIZE]; SIZE];
-        for i in 0..SIZE * SIZE {
-            let pos = UPPER_LEFT >> i;
-            if self.0 & pos != 0 {
-                m[i / SIZE][i % SIZE] = true;
-            }
+    pub fn is_empty(&self) -> bool {
+        self.0 == <Size<N> as Geometry>::Bits::ZERO
+    }
+    pub fn contains(&self, pos: Position<N>) -> bool {
+        self.0 & pos.0 != <Size<N> as Geometry>::Bits::ZERO
+    }
+    /// Iterates from a1 to the last square, row by row, like the board is
+    /// printed.
+    pub fn iter(&self) -> PositionsIter<N> {
+        PositionsIter(self.0)
+    }
+    /// The `n`th position in `iter` order.
+    pub fn nth(&self, n: usize) -> Option<Position<N>> {
+        self.iter().nth(n)
+    }
+    pub fn choose<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<Position<N>> {
+        if self.is_empty() {
+            return None;
+        }
+        self.nth(rng.gen_range(0..self.count()))
+    }
+    pub fn to_map(&self) -> [[bool; N]; N] {
+        let mut m = [[false; N]; N];
+        for pos in self {
+            let i = pos.to_idx();
+            m[i / N][i % N] = true;
         }
         m
     }
-    pub fn to_position_list(&self) -> Vec<Position> {
-        let mut v = vec![];
-        for i in 0..SIZE * SIZE {
-            let pos = UPPER_LEFT >> i;
-            if self.0 & pos != 0 {
-                v.push(Position(pos));
+    #[cfg(feature = "alloc")]
+    pub fn to_position_list(&self) -> Vec<Position<N>> {
+        self.iter().collect()
+    }
+}
+#[cfg(feature = "alloc")]
+impl<const N: usize> From<Positions<N>> for Vec<(usize, usize)>
+where
+    Size<N>: Geometry,
+{
+    fn from(p: Positions<N>) -> Self {
+        p.iter()
+            .map(|pos| (pos.to_idx() / N, pos.to_idx() % N))
+            .collect()
+    }
+}
+
+/// Iterator over the set bits of `Positions`, one leading zero count per step.
+#[derive(Clone, Debug)]
+pub struct PositionsIter<const N: usize = SIZE>(<Size<N> as Geometry>::Bits)
+where
+    Size<N>: Geometry;
+impl<const N: usize> PositionsIter<N>
+where
+    Size<N>: Geometry,
+{
+    // the highest set bit
+    fn first(&self) -> <Size<N> as Geometry>::Bits {
+        let skip = self.0.leading_zeros() - Size::<N>::FULL.leading_zeros();
+        Size::<N>::square(0, 0) >> skip as usize
+    }
+}
+impl<const N: usize> Iterator for PositionsIter<N>
+where
+    Size<N>: Geometry,
+{
+    type Item = Position<N>;
+    fn next(&mut self) -> Option<Position<N>> {
+        if self.0 == <Size<N> as Geometry>::Bits::ZERO {
+            return None;
+        }
+        let pos = self.first();
+        self.0 = self.0 ^ pos;
+        Some(Position(pos))
+    }
+    fn nth(&mut self, n: usize) -> Option<Position<N>> {
+        for _ in 0..n.min(N * N) {
+            if self.0 == <Size<N> as Geometry>::Bits::ZERO {
+                break;
             }
+            self.0 = self.0 ^ self.first();
         }
-        v
+        self.next()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.0.count_ones() as usize;
+        (n, Some(n))
+    }
+    fn count(self) -> usize {
+        self.0.count_ones() as usize
     }
 }
-impl From<Positions> for Vec<(usize, usize)> {
-    fn from(p: Positions) -> Self {
-        let mut ps = vec![];
-        for i in 0..SIZE * SIZE {
-            let pos = UPPER_LEFT >> i;
-            if p.0 & pos != 0 {
-                ps.push((i / SIZE, i % SIZE));
-            }
+impl<const N: usize> DoubleEndedIterator for PositionsIter<N>
+where
+    Size<N>: Geometry,
+{
+    fn next_back(&mut self) -> Option<Position<N>> {
+        if self.0 == <Size<N> as Geometry>::Bits::ZERO {
+            return None;
         }
-        ps
+        let pos = self.0 & self.0.wrapping_neg();
+        self.0 = self.0 ^ pos;
+        Some(Position(pos))
     }
 }
-#[derive(Clone, Copy)]
-pub struct Board {
-    pub turn: Stone,
-    pub black: u64,
-    pub white: u64,
+impl<const N: usize> ExactSizeIterator for PositionsIter<N> where Size<N>: Geometry {}
+impl<const N: usize> core::iter::FusedIterator for PositionsIter<N> where Size<N>: Geometry {}
+impl<const N: usize> IntoIterator for Positions<N>
+where
+    Size<N>: Geometry,
+{
+    type Item = Position<N>;
+    type IntoIter = PositionsIter<N>;
+    fn into_iter(self) -> PositionsIter<N> {
+        self.iter()
+    }
+}
+impl<const N: usize> IntoIterator for &Positions<N>
+where
+    Size<N>: Geometry,
+{
+    type Item = Position<N>;
+    type IntoIter = PositionsIter<N>;
+    fn into_iter(self) -> PositionsIter<N> {
+        self.iter()
+    }
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct SizedBoard<const N: usize>
+where
+    Size<N>: Geometry,
+{
+    turn: Stone,
+    black: <Size<N> as Geometry>::Bits,
+    white: <Size<N> as Geometry>::Bits,
 }
-#[derive(Debug, Error)]
-enum BoardError {
+pub type Board = SizedBoard<SIZE>;
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum BoardError {
     #[error("illegal position {0}")]
-    IllegalPositionError(Position),
-    #[error("the game is alredy over")]
-    AlredyGameOverError,
+    IllegalPosition(Position),
+    #[error("illegal square ({0}, {1})")]
+    IllegalSquare(usize, usize),
+    #[error("the game is already over")]
+    GameOver,
+    #[error("pass while a legal move exists")]
+    IllegalPass,
+    #[error("invalid square, expected [a-h][1-8]")]
+    InvalidNotation,
+    #[error("invalid character {0:?} in position string")]
+    InvalidCharacter(char),
+    #[error("position string has {0} squares, expected 64")]
+    InvalidLength(usize),
+    #[error("position string has no side to move")]
+    MissingTurn,
+    #[error("black and white discs overlap")]
+    OverlappingDiscs,
+    #[error("discs outside the board")]
+    OutsideBoard,
+}
+/// What `Board::make_move` changed, enough to take the move back.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Undo<const N: usize = SIZE>
+where
+    Size<N>: Geometry,
+{
+    pub mv: Move<N>,
+    pub flipped: <Size<N> as Geometry>::Bits,
+    pub turn: Stone,
 }
 type BoardArray = [[Option<Stone>; SIZE]; SIZE];
 
 const BLACK_STONE_STRING: &str = "⚪️";
 const WHITE_STONE_STRING: &str = "⚫️";
 
-impl fmt::Display for Board {
+impl<const N: usize> fmt::Display for SizedBoard<N>
+where
+    Size<N>: Geometry,
+{
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut s = "  a b c d e f g h\n".to_string();
-        for i in 0..SIZE * SIZE {
-            if i % SIZE == 0 {
-                s.push(std::char::from_digit((i / SIZE) as u32, 10).unwrap());
+        // row labels are right aligned, so "10" needs two columns
+        let label_width = if N >= 10 { 2 } else { 1 };
+        write!(f, "{:w$}", "", w = label_width)?;
+        for x in 0..N {
+            write!(f, " {}", (b'a' + x as u8) as char)?;
+        }
+        writeln!(f)?;
+        for i in 0..N * N {
+            if i % N == 0 {
+                write!(f, "{:>w$}", i / N + 1, w = label_width)?;
             }
-            let pos = UPPER_LEFT >> i;
-            let stone = match (self.black & pos != 0, self.white & pos != 0) {
+            let pos = Size::<N>::square(i % N, i / N);
+            let zero = <Size<N> as Geometry>::Bits::ZERO;
+            let stone = match (self.black & pos != zero, self.white & pos != zero) {
                 (true, false) => BLACK_STONE_STRING,
                 (false, true) => WHITE_STONE_STRING,
                 (false, false) => "・",
                 _ => unreachable!(),
             };
-            s.push_str(stone);
-            if i % SIZE == SIZE - 1 {
-                s.push('\n');
+            write!(f, "{}", stone)?;
+            if i % N == N - 1 {
+                writeln!(f)?;
             }
         }
-        write!(f, "{}", s)
+        Ok(())
     }
 }
 impl From<Board> for BoardArray {
@@ -155,55 +365,127 @@ impl From<Board> for BoardArray {
         board_array
     }
 }
-impl Board {
+impl<const N: usize> SizedBoard<N>
+where
+    Size<N>: Geometry,
+{
     pub fn new() -> Self {
-        Board {
+        let (black, white) = Size::<N>::initial();
+        SizedBoard {
             turn: Stone::Black,
-            black: 0x0000000810000000,
-            white: 0x0000001008000000,
+            black,
+            white,
+        }
+    }
+    /// Builds a board from raw bitboards.
+    ///
+    /// If `turn` has no legal move but the opponent has, the opponent is to
+    /// move instead. Parsed boards (`FromStr`, serde and GGF) keep the side
+    /// to move as written instead.
+    pub fn from_bitboards(
+        black: <Size<N> as Geometry>::Bits,
+        white: <Size<N> as Geometry>::Bits,
+        turn: Stone,
+    ) -> Result<Self, BoardError> {
+        let zero = <Size<N> as Geometry>::Bits::ZERO;
+        if black & white != zero {
+            return Err(BoardError::OverlappingDiscs);
+        }
+        if (black | white) & !Size::<N>::FULL != zero {
+            return Err(BoardError::OutsideBoard);
+        }
+        let mut board = Self::from_raw(black, white, turn);
+        if board.must_pass() {
+            board.turn = turn.reverse();
         }
+        Ok(board)
+    }
+    // for positions that are valid by construction, keeping the side to move
+    pub(crate) fn from_raw(
+        black: <Size<N> as Geometry>::Bits,
+        white: <Size<N> as Geometry>::Bits,
+        turn: Stone,
+    ) -> Self {
+        SizedBoard { turn, black, white }
+    }
+    pub fn turn(&self) -> Stone {
+        self.turn
+    }
+    pub fn black(&self) -> <Size<N> as Geometry>::Bits {
+        self.black
+    }
+    pub fn white(&self) -> <Size<N> as Geometry>::Bits {
+        self.white
     }
     pub fn init(&mut self) {
-        self.black = 0x0000000810000000;
-        self.white = 0x0000001008000000;
+        let (black, white) = Size::<N>::initial();
+        self.black = black;
+        self.white = white;
     }
-    pub fn get_legal_moves(&self) -> Positions {
-        let ret = if self.turn == Stone::Black {
-            legal_move(self.black, self.white)
+    /// (side to move, opponent)
+    pub(crate) fn players(&self) -> (<Size<N> as Geometry>::Bits, <Size<N> as Geometry>::Bits) {
+        if self.turn == Stone::Black {
+            (self.black, self.white)
         } else {
-            legal_move(self.white, self.black)
-        };
-        Positions(ret)
+            (self.white, self.black)
+        }
+    }
+    pub fn legal_bits(&self) -> <Size<N> as Geometry>::Bits {
+        let (player, opponent) = self.players();
+        Size::<N>::legal_move(player, opponent)
+    }
+    fn opponent_legal_bits(&self) -> <Size<N> as Geometry>::Bits {
+        let (player, opponent) = self.players();
+        Size::<N>::legal_move(opponent, player)
     }
+    /// Neither player has a legal move.
     pub fn finished(&self) -> bool {
-        self.get_legal_moves().0 == 0
+        let zero = <Size<N> as Geometry>::Bits::ZERO;
+        self.legal_bits() == zero && self.opponent_legal_bits() == zero
     }
-    pub fn put(&mut self, pos: Position) -> Result<()> {
-        if pos.0 & self.get_legal_moves().0 == 0 {
-            return Err(BoardError::IllegalPositionError(pos).into());
+    /// The side to move has no legal move but the opponent has.
+    pub fn must_pass(&self) -> bool {
+        let zero = <Size<N> as Geometry>::Bits::ZERO;
+        self.legal_bits() == zero && self.opponent_legal_bits() != zero
+    }
+    // places a stone for the side to move and flips, without any checks
+    fn flip(&mut self, pos: <Size<N> as Geometry>::Bits) {
+        let (player, opponent) = self.players();
+        let rev = Size::<N>::reverse(player, opponent, pos);
+        let (player, opponent) = (player ^ (pos | rev), opponent ^ rev);
+        if self.turn == Stone::Black {
+            self.black = player;
+            self.white = opponent;
+        } else {
+            self.white = player;
+            self.black = opponent;
         }
+    }
+    /// Plays column `x`, row `y` (both from 0) and passes for the opponent
+    /// if they have no legal move.
+    pub fn put_at(&mut self, x: usize, y: usize) -> Result<(), BoardError> {
         if self.finished() {
-            return Err(BoardError::AlredyGameOverError.into());
+            return Err(BoardError::GameOver);
         }
-        if self.turn == Stone::Black {
-            let (black, white) = put(self.black, self.white, pos.0);
-            self.black = black;
-            self.white = white;
-            self.turn = if legal_move(self.white, self.black) == 0 {
-                Stone::Black
-            } else {
-                Stone::White
-            };
-        } else {
-            let (white, black) = put(self.white, self.black, pos.0);
-            self.black = black;
-            self.white = white;
-            self.turn = if legal_move(self.black, self.white) == 0 {
-                Stone::White
-            } else {
-                Stone::Black
-            };
+        let zero = <Size<N> as Geometry>::Bits::ZERO;
+        if x >= N || y >= N || Size::<N>::square(x, y) & self.legal_bits() == zero {
+            return Err(BoardError::IllegalSquare(x, y));
+        }
+        self.flip(Size::<N>::square(x, y));
+        if self.opponent_legal_bits() != zero {
+            self.turn = self.turn.reverse();
+        }
+        Ok(())
+    }
+    /// Passes for the side to move, which must have no legal move.
+    pub fn pass(&mut self) -> Result<(), BoardError> {
+        if self.finished() {
+            return Err(BoardError::GameOver);
+        }
+        if !self.must_pass() {
+            return Err(BoardError::IllegalPass);
         }
+        self.turn = self.turn.reverse();
         Ok(())
     }
     pub fn count_stone(&self) -> StoneCount {
@@ -212,6 +494,113 @@ impl Board {
             white: self.white.count_ones() as usize,
         }
     }
+    pub fn get_legal_moves(&self) -> Positions<N> {
+        Positions(self.legal_bits())
+    }
+    /// Discs that playing `pos` would flip, empty if `pos` is not a legal move.
+    pub fn flips(&self, pos: Position<N>) -> Positions<N> {
+        let zero = <Size<N> as Geometry>::Bits::ZERO;
+        if pos.0 & self.legal_bits() == zero {
+            return Positions(zero);
+        }
+        let (player, opponent) = self.players();
+        Positions(Size::<N>::reverse(player, opponent, pos.0))
+    }
+    /// The board after `put(pos)`, leaving `self` unchanged.
+    pub fn preview(&self, pos: Position<N>) -> Result<Self, BoardError> {
+        let mut board = *self;
+        board.put(pos)?;
+        Ok(board)
+    }
+    // `IllegalPosition` holds an 8x8 square, other sizes report coordinates
+    fn illegal(pos: Position<N>) -> BoardError {
+        let (x, y) = (pos.to_idx() % N, pos.to_idx() / N);
+        if N == SIZE {
+            BoardError::IllegalPosition(Position::<SIZE>::new(x, y))
+        } else {
+            BoardError::IllegalSquare(x, y)
+        }
+    }
+    /// Plays a move without passing for the opponent afterwards.
+    pub fn apply(&mut self, mv: Move<N>) -> Result<(), BoardError> {
+        match mv {
+            Move::Play(pos) => {
+                if self.finished() {
+                    return Err(BoardError::GameOver);
+                }
+                if pos.0 & self.legal_bits() == <Size<N> as Geometry>::Bits::ZERO {
+                    return Err(Self::illegal(pos));
+                }
+                self.flip(pos.0);
+                self.turn = self.turn.reverse();
+                Ok(())
+            }
+            Move::Pass => self.pass(),
+        }
+    }
+    /// Plays a stone and passes for the opponent if they have no legal move.
+    pub fn put(&mut self, pos: Position<N>) -> Result<(), BoardError> {
+        let player = self.turn;
+        self.apply(Move::Play(pos))?;
+        if self.legal_bits() == <Size<N> as Geometry>::Bits::ZERO {
+            self.turn = player;
+        }
+        Ok(())
+    }
+    /// Plays a move for search without any legality checks or automatic
+    /// passes. The returned record restores the position with `unmake_move`.
+    pub fn make_move(&mut self, mv: Move<N>) -> Undo<N> {
+        #[cfg(debug_assertions)]
+        let before = *self;
+        let turn = self.turn;
+        let flipped = match mv {
+            Move::Play(pos) => {
+                let (player, opponent) = self.players();
+                let flipped = Size::<N>::reverse(player, opponent, pos.0);
+                let (player, opponent) = (player ^ (pos.0 | flipped), opponent ^ flipped);
+                if turn == Stone::Black {
+                    self.black = player;
+                    self.white = opponent;
+                } else {
+                    self.white = player;
+                    self.black = opponent;
+                }
+                flipped
+            }
+            Move::Pass => <Size<N> as Geometry>::Bits::ZERO,
+        };
+        self.turn = turn.reverse();
+        #[cfg(debug_assertions)]
+        if let Move::Play(pos) = mv {
+            let mut expected = before;
+            debug_assert!(expected.put(pos).is_ok(), "illegal move {}", pos);
+            debug_assert_eq!((self.black, self.white), (expected.black, expected.white));
+            debug_assert!(
+                self.turn == expected.turn
+                    || self.legal_bits() == <Size<N> as Geometry>::Bits::ZERO
+            );
+        }
+        Undo { mv, flipped, turn }
+    }
+    /// Takes back the move that returned `undo`.
+    pub fn unmake_move(&mut self, undo: Undo<N>) {
+        let placed = match undo.mv {
+            Move::Play(pos) => pos.0,
+            Move::Pass => <Size<N> as Geometry>::Bits::ZERO,
+        };
+        let (player, opponent) = if undo.turn == Stone::Black {
+            (&mut self.black, &mut self.white)
+        } else {
+            (&mut self.white, &mut self.black)
+        };
+        debug_assert_eq!(*player & (placed | undo.flipped), placed | undo.flipped);
+        *player = *player ^ (placed | undo.flipped);
+        *opponent = *opponent ^ undo.flipped;
+        self.turn = undo.turn;
+    }
+}
+
+impl Board {
     pub fn create_board_array(&self) -> BoardArray {
         let mut board_array = [[None; SIZE]; SIZE];
         for i in 0..SIZE * SIZE {
@@ -226,8 +615,70 @@ impl Board {
     }
 }
 
-impl Default for Board {
+impl<const N: usize> Default for SizedBoard<N>
+where
+    Size<N>: Geometry,
+{
     fn default() -> Self {
         Self::new()
     }
 }
+
+impl Board {
+    /// Writes the board as 64 squares (`X` black, `O` white, `-` empty)
+    /// from a1 to h8, followed by the side to move.
+    #[cfg(feature = "alloc")]
+    pub fn to_position_string(&self) -> String {
+        let mut s = String::with_capacity(SIZE * SIZE + 2);
+        for i in 0..SIZE * SIZE {
+            let pos = UPPER_LEFT >> i;
+            s.push(if self.black & pos != 0 {
+                'X'
+            } else if self.white & pos != 0 {
+                'O'
+            } else {
+                '-'
+            });
+        }
+        s.push(' ');
+        s.push(if self.turn == Stone::Black { 'X' } else { 'O' });
+        s
+    }
+}
+
+/// Parses the strings written by `to_position_string`, also accepting OBF
+/// lines (`"<board> <turn>; f5:+2; ..."`). Deserializing a `Board` goes
+/// through this too.
+///
+/// Unlike `Board::from_bitboards`, the side to move is kept as written even
+/// when it has to pass: a position string is often a snapshot taken between
+/// a move and the recorded pass that follows it, and moving the turn would
+/// make that pass illegal. Use `from_bitboards(b.black(), b.white(), b.turn())`
+/// to normalize a parsed board.
+impl FromStr for Board {
+    type Err = BoardError;
+    fn from_str(s: &str) -> Result<Self, BoardError> {
+        let s = s.split(';').next().unwrap_or_default();
+        let mut chars = s.chars().filter(|c| !c.is_whitespace());
+        let (mut black, mut white) = (0, 0);
+        for i in 0..SIZE * SIZE {
+            match chars.next() {
+                Some('X' | 'x' | '*') => black |= UPPER_LEFT >> i,
+                Some('O' | 'o') => white |= UPPER_LEFT >> i,
+                Some('-' | '.') => {}
+                Some(c) => return Err(BoardError::InvalidCharacter(c)),
+                None => return Err(BoardError::InvalidLength(i)),
+            }
+        }
+        let turn = match chars.next() {
+            Some('X' | 'x' | '*' | 'B' | 'b') => Stone::Black,
+            Some('O' | 'o' | 'W' | 'w') => Stone::White,
+            Some(c) => return Err(BoardError::InvalidCharacter(c)),
+            None => return Err(BoardError::MissingTurn),
+        };
+        if let Some(c) = chars.next() {
+            return Err(BoardError::InvalidCharacter(c));
+        }
+        Ok(Board::from_raw(black, white, turn))
+    }
+}
@@ -0,0 +1,302 @@
+use core::cmp::Ordering;
+
+use crate::{
+    features::{neighbours, CORNERS},
+    othello_logic::{legal_move, put},
+    search::{negascout, OrderedMoves},
+    stability::stable_discs,
+    Board, Move, Position,
+};
+
+// below these many empties the bookkeeping costs more than the nodes it saves
+const HASH_MIN_EMPTIES: u32 = 7;
+const FASTEST_FIRST_MIN_EMPTIES: u32 = 6;
+const STABILITY_MIN_EMPTIES: u32 = 5;
+// 2^20 entries of 24 bytes
+const TABLE_BITS: u32 = 20;
+// a1-d4, e1-h4, a5-d8, e5-h8
+const QUADRANTS: [u64; 4] = [
+    0xf0f0f0f000000000,
+    0x0f0f0f0f00000000,
+    0x00000000f0f0f0f0,
+    0x000000000f0f0f0f,
+];
+
+/// The result of `Solver::solve` or `Solver::solve_wld`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Solution {
+    /// `Move::Pass` when the side to move has to pass, `None` when the game
+    /// is already over.
+    pub best: Option<Move>,
+    /// The final disc differential for the side to move with perfect play,
+    /// empties going to the winner like `GameOutcome`. Only its sign (1, 0
+    /// or -1) for `solve_wld`.
+    pub score: i32,
+}
+
+// bounds on the exact score of a position; fail-soft search keeps them valid
+// whatever window they were found with, so an old entry is still correct,
+// only less likely to be needed
+#[derive(Clone, Copy, Debug, Default)]
+struct Entry {
+    player: u64,
+    opponent: u64,
+    lower: i8,
+    upper: i8,
+    // 0 for an unused slot, positions with fewer empties aren't stored
+    empties: u8,
+    // square index of the best move, 64 for none
+    best: u8,
+    // the solve that last stored this entry
+    generation: u8,
+}
+
+impl Entry {
+    fn best(&self) -> u64 {
+        if self.best < 64 {
+            1 << (63 - self.best)
+        } else {
+            0
+        }
+    }
+}
+
+// one position per slot; a position with more empties took more search to
+// find, so it keeps its slot against one with fewer from the same solve,
+// while entries from earlier solves can always be replaced
+struct Table {
+    entries: Box<[Entry]>,
+    generation: u8,
+}
+
+impl Table {
+    fn new() -> Self {
+        Table {
+            entries: vec![Entry::default(); 1 << TABLE_BITS].into_boxed_slice(),
+            generation: 0,
+        }
+    }
+    // called at the start of every solve
+    fn new_generation(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+    }
+    fn index(player: u64, opponent: u64) -> usize {
+        let hash =
+            player.wrapping_mul(0x9e3779b97f4a7c15) ^ opponent.wrapping_mul(0xc2b2ae3d27d4eb4f);
+        (hash >> (64 - TABLE_BITS)) as usize
+    }
+    fn get(&self, player: u64, opponent: u64) -> Option<&Entry> {
+        let entry = &self.entries[Self::index(player, opponent)];
+        if entry.empties != 0 && entry.player == player && entry.opponent == opponent {
+            Some(entry)
+        } else {
+            None
+        }
+    }
+    // records a fail-soft `score` searched with the window (alpha, beta)
+    fn store(&mut self, player: u64, opponent: u64, score: i32, alpha: i32, beta: i32, best: u64) {
+        let empties = (!(player | opponent)).count_ones() as u8;
+        let entry = &mut self.entries[Self::index(player, opponent)];
+        if entry.player != player || entry.opponent != opponent {
+            if entry.generation == self.generation && entry.empties > empties {
+                return;
+            }
+            *entry = Entry {
+                player,
+                opponent,
+                lower: -64,
+                upper: 64,
+                empties,
+                best: 64,
+                generation: self.generation,
+            };
+        }
+        entry.generation = self.generation;
+        if score > alpha {
+            entry.lower = entry.lower.max(score as i8);
+            // only a move that beat alpha is worth trying first next time
+            entry.best = best.leading_zeros() as u8;
+        }
+        if score < beta {
+            entry.upper = entry.upper.min(score as i8);
+        }
+    }
+    fn clear(&mut self) {
+        self.entries.fill(Entry::default());
+    }
+}
+
+fn final_score(player: u64, opponent: u64) -> i32 {
+    let p = player.count_ones() as i32;
+    let o = opponent.count_ones() as i32;
+    let empties = 64 - p - o;
+    match p.cmp(&o) {
+        Ordering::Greater => p - o + empties,
+        Ordering::Less => p - o - empties,
+        Ordering::Equal => 0,
+    }
+}
+
+// empty squares in the quadrants with an odd number of empties, the fixed
+// quadrants are cheaper than `features::empty_regions` for move ordering
+fn odd_squares(empty: u64) -> u64 {
+    QUADRANTS
+        .iter()
+        .map(|&q| q & empty)
+        .filter(|q| q.count_ones() % 2 == 1)
+        .fold(0, |acc, q| acc | q)
+}
+
+/// Exact alpha-beta (negascout) search to the end of the game.
+///
+/// Moves are ordered by the fewest opponent replies (fastest first) and then
+/// by quadrant parity, the opponent's stable discs cut off lines that can't
+/// reach alpha, and bounds are kept between calls in a fixed 24 MB hash
+/// table.
+///
+/// 20 empties take seconds, and each extra empty multiplies that by roughly
+/// three.
+pub struct Solver {
+    table: Table,
+    nodes: u64,
+}
+
+impl Default for Solver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Solver {
+    pub fn new() -> Self {
+        Solver {
+            table: Table::new(),
+            nodes: 0,
+        }
+    }
+    /// Nodes visited by the last solve.
+    pub fn nodes(&self) -> u64 {
+        self.nodes
+    }
+    pub fn clear(&mut self) {
+        self.table.clear();
+    }
+    /// The exact final score and a move that reaches it.
+    pub fn solve(&mut self, board: &Board) -> Solution {
+        self.solve_window(board, -65, 65)
+    }
+    /// Only whether the side to move wins, draws or loses, which is cheaper.
+    pub fn solve_wld(&mut self, board: &Board) -> Solution {
+        let solution = self.solve_window(board, -1, 1);
+        Solution {
+            score: solution.score.signum(),
+            ..solution
+        }
+    }
+
+    fn solve_window(&mut self, board: &Board, alpha: i32, beta: i32) -> Solution {
+        self.nodes = 0;
+        self.table.new_generation();
+        let (player, opponent) = board.players();
+        if board.finished() {
+            return Solution {
+                best: None,
+                score: final_score(player, opponent),
+            };
+        }
+        let moves = legal_move(player, opponent);
+        if moves == 0 {
+            return Solution {
+                best: Some(Move::Pass),
+                score: -self.search(opponent, player, -beta, -alpha, true),
+            };
+        }
+        let hint = self.table.get(player, opponent).map_or(0, Entry::best);
+        let (score, best) = self.search_moves(player, opponent, moves, hint, alpha, beta);
+        Solution {
+            best: Some(Move::Play(Position(best))),
+            score,
+        }
+    }
+
+    fn search(&mut self, player: u64, opponent: u64, alpha: i32, beta: i32, passed: bool) -> i32 {
+        self.nodes += 1;
+        let empty = !(player | opponent);
+        if empty == 0 {
+            return final_score(player, opponent);
+        }
+        let moves = legal_move(player, opponent);
+        if moves == 0 {
+            if passed {
+                return final_score(player, opponent);
+            }
+            return -self.search(opponent, player, -beta, -alpha, true);
+        }
+        let empties = empty.count_ones();
+
+        // the opponent keeps its stable discs, so the score is at most
+        // 64 - 2 * stable, which can only be below alpha if alpha is this high
+        if empties >= STABILITY_MIN_EMPTIES && alpha >= 64 - 2 * opponent.count_ones() as i32 {
+            let upper = 64 - 2 * stable_discs(opponent, player).count_ones() as i32;
+            if upper <= alpha {
+                return upper;
+            }
+        }
+
+        let mut hint = 0;
+        if empties >= HASH_MIN_EMPTIES {
+            if let Some(entry) = self.table.get(player, opponent) {
+                let (lower, upper) = (entry.lower as i32, entry.upper as i32);
+                if lower >= beta || lower == upper {
+                    return lower;
+                }
+                if upper <= alpha {
+                    return upper;
+                }
+                hint = entry.best();
+            }
+        }
+
+        let (score, best) = self.search_moves(player, opponent, moves, hint, alpha, beta);
+
+        if empties >= HASH_MIN_EMPTIES {
+            self.table.store(player, opponent, score, alpha, beta, best);
+        }
+        score
+    }
+
+    fn search_moves(
+        &mut self,
+        player: u64,
+        opponent: u64,
+        moves: u64,
+        hint: u64,
+        alpha: i32,
+        beta: i32,
+    ) -> (i32, u64) {
+        let empty = !(player | opponent);
+        let fastest_first = empty.count_ones() >= FASTEST_FIRST_MIN_EMPTIES;
+        let odd = odd_squares(empty);
+        let ordered = OrderedMoves::new(moves, |pos| {
+            let even = (odd & pos == 0) as i32;
+            if pos == hint {
+                i32::MIN
+            } else if fastest_first {
+                // opponent replies with corners counted twice, then the
+                // empties next to our discs that the opponent could use later
+                let (player, opponent) = put(player, opponent, pos);
+                let replies = legal_move(opponent, player);
+                let potential = neighbours(player) & !(player | opponent);
+                let mobility = replies.count_ones() + (replies & CORNERS).count_ones();
+                2 * (4 * mobility + potential.count_ones()) as i32 + even
+            } else {
+                even
+            }
+        });
+        negascout(&ordered, alpha, beta, |pos, alpha, beta| {
+            let (player, opponent) = put(player, opponent, pos);
+            Some(-self.search(opponent, player, -beta, -alpha, false))
+        })
+        .expect("the solver never aborts")
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{
+    othello_logic::legal_move,
+    stability::{stable_discs, DIRECTIONS},
+    Board,
+};
+
+pub(crate) const CORNERS: u64 = 0x8100000000000081;
+const X_SQUARES: u64 = 0x0042000000004200;
+const C_SQUARES: u64 = 0x4281000000008142;
+
+/// Counts for one side.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct SideFeatures {
+    pub discs: u8,
+    /// Legal moves if this side were to move.
+    pub mobility: u8,
+    /// Empty squares next to an opponent disc.
+    pub potential_mobility: u8,
+    /// Own discs next to an empty square.
+    pub frontier: u8,
+    pub stable: u8,
+    pub corners: u8,
+    /// b2, g2, b7 and g7.
+    pub x_squares: u8,
+    /// The edge squares next to a corner.
+    pub c_squares: u8,
+}
+
+/// Positional features of a board, from the side to move's point of view.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Features {
+    pub player: SideFeatures,
+    pub opponent: SideFeatures,
+    pub empties: u8,
+    /// Connected regions of empty squares (including diagonal neighbours)
+    /// with an odd number of squares.
+    pub odd_regions: u8,
+    /// Connected regions of empty squares with an even number of squares.
+    pub even_regions: u8,
+}
+
+/// The connected regions of `empty`, one bitboard each.
+pub(crate) fn empty_regions(mut empty: u64) -> impl Iterator<Item = u64> {
+    core::iter::from_fn(move || {
+        if empty == 0 {
+            return None;
+        }
+        let mut region = empty & empty.wrapping_neg();
+        loop {
+            let grown = region | neighbours(region) & empty;
+            if grown == region {
+                break;
+            }
+            region = grown;
+        }
+        empty ^= region;
+        Some(region)
+    })
+}
+
+pub(crate) fn neighbours(bits: u64) -> u64 {
+    DIRECTIONS
+        .iter()
+        .fold(0, |acc, &(step, back)| acc | step(bits) | back(bits))
+}
+
+fn side(player: u64, opponent: u64) -> SideFeatures {
+    let empty = !(player | opponent);
+    let count = |bits: u64| bits.count_ones() as u8;
+    SideFeatures {
+        discs: count(player),
+        mobility: count(legal_move(player, opponent)),
+        potential_mobility: count(empty & neighbours(opponent)),
+        frontier: count(player & neighbours(empty)),
+        stable: count(stable_discs(player, opponent)),
+        corners: count(player & CORNERS),
+        x_squares: count(player & X_SQUARES),
+        c_squares: count(player & C_SQUARES),
+    }
+}
+
+impl Features {
+    pub const LEN: usize = 19;
+    /// Side to move first, then the opponent, then the empties and the odd and
+    /// even region counts.
+    pub fn to_array(&self) -> [f32; Features::LEN] {
+        let mut array = [0.0; Features::LEN];
+        for (i, side) in [self.player, self.opponent].iter().enumerate() {
+            let values = [
+                side.discs,
+                side.mobility,
+                side.potential_mobility,
+                side.frontier,
+                side.stable,
+                side.corners,
+                side.x_squares,
+                side.c_squares,
+            ];
+            for (j, &v) in values.iter().enumerate() {
+                array[i * 8 + j] = v as f32;
+            }
+        }
+        array[16] = self.empties as f32;
+        array[17] = self.odd_regions as f32;
+        array[18] = self.even_regions as f32;
+        array
+    }
+}
+
+impl Board {
+    pub fn features(&self) -> Features {
+        let (player, opponent) = self.players();
+        let empty = !(player | opponent);
+        let (odd_regions, even_regions) =
+            empty_regions(empty).fold((0, 0), |(odd, even), region| {
+                if region.count_ones() % 2 == 1 {
+                    (odd + 1, even)
+                } else {
+                    (odd, even + 1)
+                }
+            });
+        Features {
+            player: side(player, opponent),
+            opponent: side(opponent, player),
+            empties: empty.count_ones() as u8,
+            odd_regions,
+            even_regions,
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{Board, BoardError, Move, Position};
+use thiserror::Error;
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum GameError {
+    #[error("ply {0} is out of range")]
+    PlyOutOfRange(usize),
+}
+
+/// A game as a list of plies on top of `Board`, with undo/redo.
+///
+/// Passes are recorded as `Move::Pass`. `boards[i]` is the position before
+/// ply `i`, so `boards` always holds one more entry than `moves`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct GameRecord {
+    boards: Vec<Board>,
+    moves: Vec<Move>,
+    ply: usize,
+}
+
+impl GameRecord {
+    pub fn new() -> Self {
+        Self::from_board(Board::new())
+    }
+    pub fn from_board(board: Board) -> Self {
+        GameRecord {
+            boards: vec![board],
+            moves: vec![],
+            ply: 0,
+        }
+    }
+    pub fn replay(board: Board, moves: &[Position]) -> Result<Self, BoardError> {
+        let mut record = Self::from_board(board);
+        for &pos in moves {
+            record.put(pos)?;
+        }
+        Ok(record)
+    }
+    pub fn board(&self) -> &Board {
+        &self.boards[self.ply]
+    }
+    pub fn initial_board(&self) -> &Board {
+        &self.boards[0]
+    }
+    pub fn ply(&self) -> usize {
+        self.ply
+    }
+    pub fn len(&self) -> usize {
+        self.moves.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.moves.is_empty()
+    }
+    pub fn moves(&self) -> &[Move] {
+        &self.moves
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (&Board, Move)> {
+        self.boards.iter().zip(self.moves.iter().copied())
+    }
+    /// Plays a stone and records a pass for the opponent if they have no legal move.
+    pub fn put(&mut self, pos: Position) -> Result<(), BoardError> {
+        self.apply(Move::Play(pos))?;
+        if self.board().must_pass() {
+            self.apply(Move::Pass)?;
+        }
+        Ok(())
+    }
+    /// Plays a move at the current ply, dropping any moves that could be redone.
+    ///
+    /// Like `Board::put`, the mover stays the side to move once the game is over.
+    pub fn apply(&mut self, mv: Move) -> Result<(), BoardError> {
+        let mut board = *self.board();
+        board.apply(mv)?;
+        if board.finished() {
+            board = Board::from_raw(board.black(), board.white(), board.turn().reverse());
+        }
+        self.moves.truncate(self.ply);
+        self.boards.truncate(self.ply + 1);
+        self.moves.push(mv);
+        self.boards.push(board);
+        self.ply += 1;
+        Ok(())
+    }
+    /// Steps back one move. Passes are stepped over, so undo and redo never
+    /// stop on a position where the side to move has to pass.
+    pub fn undo(&mut self) -> bool {
+        if self.ply == 0 {
+            return false;
+        }
+        self.ply -= 1;
+        if self.ply > 0 && self.moves[self.ply] == Move::Pass {
+            self.ply -= 1;
+        }
+        true
+    }
+    pub fn redo(&mut self) -> bool {
+        if self.ply == self.moves.len() {
+            return false;
+        }
+        self.ply += 1;
+        if self.ply < self.moves.len() && self.moves[self.ply] == Move::Pass {
+            self.ply += 1;
+        }
+        true
+    }
+    /// Goes to the position before ply `ply`. Like `redo`, a forced pass is
+    /// stepped over, so jumping to it lands on the position after the pass.
+    pub fn jump(&mut self, ply: usize) -> Result<(), GameError> {
+        if ply > self.moves.len() {
+            return Err(GameError::PlyOutOfRange(ply));
+        }
+        self.ply = ply;
+        if self.ply < self.moves.len() && self.moves[self.ply] == Move::Pass {
+            self.ply += 1;
+        }
+        Ok(())
+    }
+    pub fn rewind(&mut self) {
+        self.ply = 0;
+    }
+}
+
+impl Default for GameRecord {
+    fn default() -> Self {
+        Self::new()
+    }
+}
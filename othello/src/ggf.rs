@@ -0,0 +1,388 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use crate::{game::GameRecord, Board, BoardError, Move, Position, Stone, SIZE, UPPER_LEFT};
+use thiserror::Error;
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum GgfError {
+    #[error("malformed GGF: {0}")]
+    Syntax(String),
+    #[error("missing property {0}")]
+    MissingProperty(&'static str),
+    #[error("invalid value {1:?} for property {0}")]
+    InvalidValue(String, String),
+    #[error("unsupported game {0:?}")]
+    UnsupportedGame(String),
+    #[error("unsupported board size {0}, only 8x8 is supported")]
+    UnsupportedSize(usize),
+    #[error("unsupported variant {0:?}")]
+    UnsupportedVariant(String),
+    #[error("move by the wrong player at ply {0}")]
+    OutOfTurn(usize),
+    #[error("illegal move {1} at ply {0}")]
+    IllegalMove(usize, Position),
+    #[error("pass at ply {0} while a legal move exists")]
+    IllegalPass(usize),
+    #[error(transparent)]
+    Board(#[from] BoardError),
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct TimeControl {
+    pub main: u32,
+    pub extension: u32,
+    pub increment: u32,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ResultKind {
+    Normal,
+    Resigned,
+    Timeout,
+    Agreed,
+}
+
+/// Final result as the disc differential from black's point of view.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct GgfResult {
+    pub score: f64,
+    pub kind: ResultKind,
+}
+
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct MoveInfo {
+    pub eval: Option<f64>,
+    pub time: Option<f64>,
+}
+
+/// A game in Generic Game Format.
+///
+/// `move_info` is aligned with `record.moves()`, passes included. The side
+/// to move in `BO` is kept even when it has to pass, because the file then
+/// records that pass as its first move.
+#[derive(Clone, Debug)]
+pub struct GgfGame {
+    pub place: Option<String>,
+    pub date: Option<String>,
+    pub black_player: String,
+    pub white_player: String,
+    pub black_rating: Option<f64>,
+    pub white_rating: Option<f64>,
+    pub time_control: Option<TimeControl>,
+    pub record: GameRecord,
+    pub move_info: Vec<MoveInfo>,
+    pub result: Option<GgfResult>,
+}
+
+/// Parses every game in a GGF file.
+pub fn parse_ggf(s: &str) -> Result<Vec<GgfGame>, GgfError> {
+    let mut games = vec![];
+    let mut rest = s;
+    while let Some(start) = rest.find("(;") {
+        let end = rest[start..]
+            .find(";)")
+            .ok_or_else(|| GgfError::Syntax("unterminated game".to_string()))?;
+        games.push(rest[start..start + end + 2].parse()?);
+        rest = &rest[start + end + 2..];
+    }
+    Ok(games)
+}
+
+fn properties(s: &str) -> Result<Vec<(&str, &str)>, GgfError> {
+    let body = s
+        .trim()
+        .strip_prefix("(;")
+        .and_then(|s| s.strip_suffix(";)"))
+        .ok_or_else(|| GgfError::Syntax("a game must be enclosed in (; ;)".to_string()))?;
+    let mut props = vec![];
+    let mut rest = body.trim_start();
+    while !rest.is_empty() {
+        let open = rest
+            .find('[')
+            .ok_or_else(|| GgfError::Syntax(format!("expected '[' in {:?}", rest)))?;
+        let close = rest[open..]
+            .find(']')
+            .ok_or_else(|| GgfError::Syntax("unterminated property value".to_string()))?;
+        let name = rest[..open].trim();
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_uppercase()) {
+            return Err(GgfError::Syntax(format!(
+                "invalid property name {:?}",
+                name
+            )));
+        }
+        props.push((name, &rest[open + 1..open + close]));
+        rest = rest[open + close + 1..].trim_start();
+    }
+    Ok(props)
+}
+
+fn invalid(name: &str, value: &str) -> GgfError {
+    GgfError::InvalidValue(name.to_string(), value.to_string())
+}
+
+fn parse_f64(name: &str, value: &str) -> Result<f64, GgfError> {
+    value.trim().parse().map_err(|_| invalid(name, value))
+}
+
+// [[hh:]mm:]ss
+fn parse_clock(value: &str) -> Option<f64> {
+    value.split(':').try_fold(0.0, |acc, part| {
+        Some(acc * 60.0 + part.parse::<f64>().ok()?)
+    })
+}
+
+fn format_clock(secs: u32) -> String {
+    format!("{:02}:{:02}", secs / 60, secs % 60)
+}
+
+fn parse_size(name: &str, value: &str) -> Result<usize, GgfError> {
+    let size: usize = value.trim().parse().map_err(|_| invalid(name, value))?;
+    if size != SIZE {
+        return Err(GgfError::UnsupportedSize(size));
+    }
+    Ok(size)
+}
+
+fn parse_type(value: &str) -> Result<(), GgfError> {
+    let value = value.trim();
+    let (size, variant) = value.split_at(
+        value
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(value.len()),
+    );
+    // random start positions ("8r", "8r20") are plain games starting from BO
+    let random_start = variant
+        .strip_prefix('r')
+        .is_some_and(|n| n.chars().all(|c| c.is_ascii_digit()));
+    if size.is_empty() || !(variant.is_empty() || random_start) {
+        return Err(GgfError::UnsupportedVariant(value.to_string()));
+    }
+    parse_size("TY", size).map(|_| ())
+}
+
+fn parse_board(value: &str) -> Result<Board, GgfError> {
+    let mut tokens = value.split_whitespace();
+    parse_size("BO", tokens.next().unwrap_or(""))?;
+    let squares: String = tokens.collect();
+    let mut chars = squares.chars();
+    let (mut black, mut white) = (0, 0);
+    for i in 0..SIZE * SIZE {
+        match chars.next() {
+            Some('*') => black |= UPPER_LEFT >> i,
+            Some('O') => white |= UPPER_LEFT >> i,
+            Some('-') => {}
+            _ => return Err(invalid("BO", value)),
+        }
+    }
+    let turn = match (chars.next(), chars.next()) {
+        (Some('*'), None) => Stone::Black,
+        (Some('O'), None) => Stone::White,
+        _ => return Err(invalid("BO", value)),
+    };
+    // a pass at the start is recorded as a move, so the side to move is kept
+    Ok(Board::from_raw(black, white, turn))
+}
+
+fn parse_result(value: &str) -> Result<GgfResult, GgfError> {
+    let (score, kind) = match value.split_once(':') {
+        Some((score, "r")) => (score, ResultKind::Resigned),
+        Some((score, "t")) => (score, ResultKind::Timeout),
+        Some((score, "s")) => (score, ResultKind::Agreed),
+        Some(_) => return Err(invalid("RE", value)),
+        None => (value, ResultKind::Normal),
+    };
+    Ok(GgfResult {
+        score: parse_f64("RE", score)?,
+        kind,
+    })
+}
+
+fn parse_time_control(value: &str) -> Result<TimeControl, GgfError> {
+    let parts: Vec<&str> = value.split('/').collect();
+    if parts.len() > 3 {
+        return Err(invalid("TI", value));
+    }
+    let mut secs = [0; 3];
+    for (s, part) in secs.iter_mut().zip(parts) {
+        if !part.is_empty() {
+            *s = parse_clock(part).ok_or_else(|| invalid("TI", value))? as u32;
+        }
+    }
+    Ok(TimeControl {
+        main: secs[0],
+        extension: secs[1],
+        increment: secs[2],
+    })
+}
+
+impl FromStr for GgfGame {
+    type Err = GgfError;
+    fn from_str(s: &str) -> Result<Self, GgfError> {
+        let props = properties(s)?;
+        let get = |name: &str| props.iter().find(|p| p.0 == name).map(|p| p.1);
+        if let Some(game) = get("GM") {
+            if !game.eq_ignore_ascii_case("othello") {
+                return Err(GgfError::UnsupportedGame(game.to_string()));
+            }
+        }
+        if let Some(ty) = get("TY") {
+            parse_type(ty)?;
+        }
+        let board = parse_board(get("BO").ok_or(GgfError::MissingProperty("BO"))?)?;
+        let mut game = GgfGame {
+            place: get("PC").map(str::to_string),
+            date: get("DT").map(str::to_string),
+            black_player: get("PB").unwrap_or_default().to_string(),
+            white_player: get("PW").unwrap_or_default().to_string(),
+            black_rating: get("RB").map(|v| parse_f64("RB", v)).transpose()?,
+            white_rating: get("RW").map(|v| parse_f64("RW", v)).transpose()?,
+            time_control: get("TI").map(parse_time_control).transpose()?,
+            record: GameRecord::from_board(board),
+            move_info: vec![],
+            result: get("RE").map(parse_result).transpose()?,
+        };
+        for &(name, value) in props.iter().filter(|p| p.0 == "B" || p.0 == "W") {
+            let stone = if name == "B" {
+                Stone::Black
+            } else {
+                Stone::White
+            };
+            let mut fields = value.split('/');
+            let square = fields.next().unwrap_or_default();
+            let info = MoveInfo {
+                eval: match fields.next() {
+                    Some(v) if !v.is_empty() => Some(parse_f64(name, v)?),
+                    _ => None,
+                },
+                time: match fields.next() {
+                    Some(v) if !v.is_empty() => {
+                        Some(parse_clock(v).ok_or_else(|| invalid(name, value))?)
+                    }
+                    _ => None,
+                },
+            };
+            let mv = if square.eq_ignore_ascii_case("pa") {
+                if !game.record.board().must_pass() {
+                    return Err(GgfError::IllegalPass(game.record.len()));
+                }
+                Move::Pass
+            } else {
+                let pos: Position = square.parse().map_err(|_| invalid(name, value))?;
+                // some files leave passes out
+                if game.record.board().must_pass() {
+                    game.record.apply(Move::Pass)?;
+                    game.move_info.push(MoveInfo::default());
+                }
+                if game.record.board().get_legal_moves().0 & pos.0 == 0 {
+                    return Err(GgfError::IllegalMove(game.record.len(), pos));
+                }
+                Move::Play(pos)
+            };
+            if stone != game.record.board().turn() {
+                return Err(GgfError::OutOfTurn(game.record.len()));
+            }
+            game.record.apply(mv)?;
+            game.move_info.push(info);
+        }
+        Ok(game)
+    }
+}
+
+fn write_option<T: Display>(f: &mut fmt::Formatter<'_>, value: Option<T>) -> fmt::Result {
+    match value {
+        Some(v) => write!(f, "{}", v),
+        None => Ok(()),
+    }
+}
+
+impl Display for GgfGame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(;GM[Othello]")?;
+        if let Some(place) = &self.place {
+            write!(f, "PC[{}]", place)?;
+        }
+        if let Some(date) = &self.date {
+            write!(f, "DT[{}]", date)?;
+        }
+        write!(f, "PB[{}]PW[{}]", self.black_player, self.white_player)?;
+        if let Some(rating) = self.black_rating {
+            write!(f, "RB[{}]", rating)?;
+        }
+        if let Some(rating) = self.white_rating {
+            write!(f, "RW[{}]", rating)?;
+        }
+        if let Some(tc) = self.time_control {
+            write!(
+                f,
+                "TI[{}/{}/{}]",
+                format_clock(tc.main),
+                if tc.extension == 0 {
+                    String::new()
+                } else {
+                    format_clock(tc.extension)
+                },
+                format_clock(tc.increment)
+            )?;
+        }
+        write!(f, "TY[{}]", SIZE)?;
+        if let Some(result) = self.result {
+            write!(f, "RE[{:+.3}", result.score)?;
+            match result.kind {
+                ResultKind::Normal => {}
+                ResultKind::Resigned => write!(f, ":r")?,
+                ResultKind::Timeout => write!(f, ":t")?,
+                ResultKind::Agreed => write!(f, ":s")?,
+            }
+            write!(f, "]")?;
+        }
+        let initial = self.record.initial_board();
+        write!(f, "BO[{} ", SIZE)?;
+        for i in 0..SIZE * SIZE {
+            let pos = UPPER_LEFT >> i;
+            let c = if initial.black() & pos != 0 {
+                '*'
+            } else if initial.white() & pos != 0 {
+                'O'
+            } else {
+                '-'
+            };
+            write!(f, "{}", c)?;
+            if i % SIZE == SIZE - 1 {
+                write!(f, " ")?;
+            }
+        }
+        let turn = if initial.turn() == Stone::Black {
+            '*'
+        } else {
+            'O'
+        };
+        write!(f, "{}]", turn)?;
+        for (i, (board, mv)) in self.record.iter().enumerate() {
+            let name = if board.turn() == Stone::Black {
+                "B"
+            } else {
+                "W"
+            };
+            // servers write squares and passes in uppercase
+            write!(f, "{}[{}", name, mv.to_string().to_ascii_uppercase())?;
+            let info = self.move_info.get(i).copied().unwrap_or_default();
+            if info.eval.is_some() || info.time.is_some() {
+                write!(f, "/")?;
+                write_option(f, info.eval)?;
+                write!(f, "/")?;
+                write_option(f, info.time)?;
+            }
+            write!(f, "]")?;
+        }
+        write!(f, ";)")
+    }
+}
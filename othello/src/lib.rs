@@ -1,6 +1,40 @@
+//! Bitboard Othello rules and players.
+//!
+//! `board`, `othello_logic` and the modules built only on them work without
+//! `std`. The `alloc` feature adds game records and notations, and the
+//! default `std` feature adds the players and utilities.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub mod board;
+#[cfg(feature = "std")]
+pub mod endgame;
+pub mod features;
+#[cfg(feature = "alloc")]
+pub mod game;
+#[cfg(feature = "alloc")]
+pub mod ggf;
+#[cfg(feature = "alloc")]
+pub mod notation;
+#[cfg(feature = "alloc")]
+pub mod opening;
 pub mod othello_logic;
+pub mod outcome;
+pub mod perft;
+#[cfg(feature = "std")]
 pub mod players;
+#[cfg(feature = "std")]
+mod search;
+#[cfg(all(feature = "serde", feature = "alloc"))]
+mod serde_impls;
+pub mod stability;
+pub mod symmetry;
+#[cfg(feature = "std")]
 pub mod utils;
+#[cfg(feature = "std")]
+pub mod wthor;
+pub mod zobrist;
 
 pub use board::*;
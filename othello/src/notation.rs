@@ -0,0 +1,71 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{game::GameRecord, Board, BoardError, Move, Position};
+use thiserror::Error;
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum NotationError {
+    #[error("illegal move {1} at ply {0}")]
+    IllegalMove(usize, Position),
+    #[error("pass at ply {0} while a legal move exists")]
+    IllegalPass(usize),
+    #[error("unexpected end of transcript")]
+    UnexpectedEnd,
+    #[error(transparent)]
+    Board(#[from] BoardError),
+}
+
+/// Parses a transcript such as `"f5d6c3d3c4"` played from the initial position.
+pub fn parse_transcript(transcript: &str) -> Result<GameRecord, NotationError> {
+    parse_transcript_from(Board::new(), transcript)
+}
+
+/// Parses a transcript played from `board`.
+///
+/// Passes may be left out or written as `pa`, `ps` or `--`, and separators
+/// between moves (whitespace, `,`) are ignored.
+pub fn parse_transcript_from(board: Board, transcript: &str) -> Result<GameRecord, NotationError> {
+    let chars: Vec<char> = transcript
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != ',')
+        .collect();
+    let mut record = GameRecord::from_board(board);
+    for token in chars.chunks(2) {
+        if token.len() != 2 {
+            return Err(NotationError::UnexpectedEnd);
+        }
+        let token: String = token.iter().collect();
+        let ply = record.len();
+        let pos = match token.parse()? {
+            Move::Pass => {
+                if !record.board().must_pass() {
+                    return Err(NotationError::IllegalPass(ply));
+                }
+                record.apply(Move::Pass)?;
+                continue;
+            }
+            Move::Play(pos) => pos,
+        };
+        if record.board().must_pass() {
+            record.apply(Move::Pass)?;
+        }
+        if record.board().get_legal_moves().0 & pos.0 == 0 {
+            return Err(NotationError::IllegalMove(record.len(), pos));
+        }
+        record.apply(Move::Play(pos))?;
+    }
+    Ok(record)
+}
+
+/// Writes the moves of `record` as a transcript, leaving passes out.
+pub fn to_transcript(record: &GameRecord) -> String {
+    record
+        .moves()
+        .iter()
+        .filter(|&&mv| mv != Move::Pass)
+        .map(|mv| mv.to_string())
+        .collect()
+}
@@ -0,0 +1,359 @@
+use alloc::vec::Vec;
+
+use crate::{game::GameRecord, Board, Move, Position};
+
+pub struct Opening {
+    pub name: &'static str,
+    /// Moves from the initial position, starting with f5.
+    pub moves: &'static [Position],
+}
+
+// a square such as "f5", checked at compile time
+const fn square(name: &str) -> Position {
+    let b = name.as_bytes();
+    assert!(b.len() == 2 && b'a' <= b[0] && b[0] <= b'h' && b'1' <= b[1] && b[1] <= b'8');
+    Position::new((b[0] - b'a') as usize, (b[1] - b'1') as usize)
+}
+
+macro_rules! squares {
+    ($($square:ident)*) => {
+        &[$(square(stringify!($square))),*]
+    };
+}
+
+pub const OPENINGS: &[Opening] = &[
+    Opening {
+        name: "Perpendicular",
+        moves: squares!(f5 d6),
+    },
+    Opening {
+        name: "Parallel",
+        moves: squares!(f5 f4),
+    },
+    Opening {
+        name: "Diagonal",
+        moves: squares!(f5 f6),
+    },
+    Opening {
+        name: "Snake",
+        moves: squares!(f5 f6 e6 f4 g7),
+    },
+    Opening {
+        name: "Pyramid",
+        moves: squares!(f5 f6 e6 f4 g6),
+    },
+    Opening {
+        name: "Heath",
+        moves: squares!(f5 f6 e6 f4 g5),
+    },
+    Opening {
+        name: "Mimura Variation II",
+        moves: squares!(f5 f6 e6 f4 g5 e7 f7 c5 e3 f3 c4 d3 c2),
+    },
+    Opening {
+        name: "Heath-Bat",
+        moves: squares!(f5 f6 e6 f4 g5 e7 e3),
+    },
+    Opening {
+        name: "Iwasaki Variation",
+        moves: squares!(f5 f6 e6 f4 g5 e7 d7),
+    },
+    Opening {
+        name: "Heath-Chimney",
+        moves: squares!(f5 f6 e6 f4 g5 d6),
+    },
+    Opening {
+        name: "Raccoon Dog",
+        moves: squares!(f5 f6 e6 f4 g4),
+    },
+    Opening {
+        name: "Rocket",
+        moves: squares!(f5 f6 e6 f4 g3),
+    },
+    Opening {
+        name: "Hamilton",
+        moves: squares!(f5 f6 e6 f4 g3 f3 g4),
+    },
+    Opening {
+        name: "Lollipop",
+        moves: squares!(f5 f6 e6 f4 g3 d6),
+    },
+    Opening {
+        name: "Rabbit",
+        moves: squares!(f5 f6 e6 f4 e3),
+    },
+    Opening {
+        name: "Chimney",
+        moves: squares!(f5 f6 e6 f4 e3 d6),
+    },
+    Opening {
+        name: "Bat",
+        moves: squares!(f5 f6 e6 f4 e3 c5 g5),
+    },
+    Opening {
+        name: "Melnikov",
+        moves: squares!(f5 f6 e6 f4 e3 c5 g5 g3 g4 f3 c4),
+    },
+    Opening {
+        name: "Rose-v-Toth",
+        moves: squares!(f5 f6 e6 f4 e3 c5 c4),
+    },
+    Opening {
+        name: "Tanida",
+        moves: squares!(f5 f6 e6 f4 e3 c5 c4 e7),
+    },
+    Opening {
+        name: "Aircraft",
+        moves: squares!(f5 f6 e6 f4 e3 c5 c4 e7 g4),
+    },
+    Opening {
+        name: "Sailboat",
+        moves: squares!(f5 f6 e6 f4 e3 c5 c4 e7 b5 e2),
+    },
+    Opening {
+        name: "Maruoka",
+        moves: squares!(f5 f6 e6 f4 e3 c5 c4 d3 f3 e2),
+    },
+    Opening {
+        name: "Landau",
+        moves: squares!(f5 f6 e6 f4 e3 c5 c4 d3 c3),
+    },
+    Opening {
+        name: "Buffalo",
+        moves: squares!(f5 f6 e6 f4 c3),
+    },
+    Opening {
+        name: "Maruoka Buffalo",
+        moves: squares!(f5 f6 e6 f4 c3 d7 f3),
+    },
+    Opening {
+        name: "Tanida Buffalo",
+        moves: squares!(f5 f6 e6 f4 c3 d6 f3 c4 c5 b4),
+    },
+    Opening {
+        name: "Hokuriku Buffalo",
+        moves: squares!(f5 f6 e6 f4 c3 c4),
+    },
+    Opening {
+        name: "Wing Variation",
+        moves: squares!(f5 f6 d3 f4),
+    },
+    Opening {
+        name: "Semi-Wing Variation",
+        moves: squares!(f5 f6 c4 f4),
+    },
+    Opening {
+        name: "Cow",
+        moves: squares!(f5 d6 c5 f4 e3),
+    },
+    Opening {
+        name: "Shaman",
+        moves: squares!(f5 d6 c5 f4 e3 c6 f3),
+    },
+    Opening {
+        name: "Inoue",
+        moves: squares!(f5 d6 c5 f4 e3 c6 e6),
+    },
+    Opening {
+        name: "Iago",
+        moves: squares!(f5 d6 c5 f4 e3 c6 e6 f6),
+    },
+    Opening {
+        name: "Bhagat",
+        moves: squares!(f5 d6 c5 f4 e3 c6 d7),
+    },
+    Opening {
+        name: "Rose",
+        moves: squares!(f5 d6 c5 f4 e3 c6 d3 f6 e6 d7),
+    },
+    Opening {
+        name: "Flat",
+        moves: squares!(f5 d6 c5 f4 e3 c6 d3 f6 e6 d7 g4),
+    },
+    Opening {
+        name: "Rotating Flat",
+        moves: squares!(f5 d6 c5 f4 e3 c6 d3 f6 e6 d7 g4 c4),
+    },
+    Opening {
+        name: "Murakami Variation",
+        moves: squares!(f5 d6 c5 f4 e3 c6 d3 f6 e6 d7 g4 c4 g6),
+    },
+    Opening {
+        name: "Rose-Birdie",
+        moves: squares!(f5 d6 c5 f4 e3 c6 d3 f6 e6 d7 g3 c4),
+    },
+    Opening {
+        name: "Greenberg",
+        moves: squares!(f5 d6 c5 f4 e3 c6 d3 f6 e6 d7 g3 c4 b4),
+    },
+    Opening {
+        name: "Horse",
+        moves: squares!(f5 d6 c5 f4 d3),
+    },
+    Opening {
+        name: "No-Cat",
+        moves: squares!(f5 d6 c4 g5),
+    },
+    Opening {
+        name: "Swallow",
+        moves: squares!(f5 d6 c4 g5 c6),
+    },
+    Opening {
+        name: "Italian",
+        moves: squares!(f5 d6 c4 d3 e6),
+    },
+    Opening {
+        name: "Cat",
+        moves: squares!(f5 d6 c4 d3 c5),
+    },
+    Opening {
+        name: "Sakaguchi",
+        moves: squares!(f5 d6 c4 d3 c5 f4 e3 f3 c2 c6),
+    },
+    Opening {
+        name: "Berner",
+        moves: squares!(f5 d6 c4 d3 c5 f4 e3 f3 c2 b4 b3),
+    },
+    Opening {
+        name: "Ganglion",
+        moves: squares!(f5 d6 c3 g5),
+    },
+    Opening {
+        name: "Tiger",
+        moves: squares!(f5 d6 c3 d3 c4),
+    },
+    Opening {
+        name: "Stephenson",
+        moves: squares!(f5 d6 c3 d3 c4 f4 f6),
+    },
+    Opening {
+        name: "No-Kung",
+        moves: squares!(f5 d6 c3 d3 c4 f4 f6 g5),
+    },
+    Opening {
+        name: "COMP'OTH",
+        moves: squares!(f5 d6 c3 d3 c4 f4 f6 f3),
+    },
+    Opening {
+        name: "Leader's Tiger",
+        moves: squares!(f5 d6 c3 d3 c4 f4 f6 b4),
+    },
+    Opening {
+        name: "Aubrey",
+        moves: squares!(f5 d6 c3 d3 c4 f4 e6),
+    },
+    Opening {
+        name: "Brightwell",
+        moves: squares!(f5 d6 c3 d3 c4 f4 e3),
+    },
+    Opening {
+        name: "Rose-Bill",
+        moves: squares!(f5 d6 c3 d3 c4 f4 c5 b3 c2),
+    },
+    Opening {
+        name: "Mainline Tiger",
+        moves: squares!(f5 d6 c3 d3 c4 f4 c5 b4 b5 c6 f3 e6 e3 g6 f6 g5 d7 g3),
+    },
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct OpeningMatch {
+    /// Longest named line whose final position the game reached.
+    pub name: &'static str,
+    /// Number of moves of that line.
+    pub len: usize,
+    /// Ply of the first move that leads to no catalogue position, `None`
+    /// while the game is still in the catalogue.
+    pub left_theory: Option<usize>,
+}
+
+// canonical positions after each move, as far as the moves are legal
+fn positions(moves: impl IntoIterator<Item = Move>) -> Vec<Board> {
+    let mut board = Board::new();
+    let mut positions = Vec::new();
+    for mv in moves {
+        if board.apply(mv).is_err() {
+            break;
+        }
+        positions.push(board.canonical().0);
+    }
+    positions
+}
+
+/// The canonical positions along every `OPENINGS` line, built once so that
+/// identifying a game only replays the game itself.
+pub struct OpeningBook {
+    lines: Vec<Vec<Board>>,
+}
+
+impl OpeningBook {
+    pub fn new() -> Self {
+        OpeningBook {
+            lines: OPENINGS
+                .iter()
+                .map(|opening| positions(opening.moves.iter().map(|&pos| Move::Play(pos))))
+                .collect(),
+        }
+    }
+    /// Finds the opening of a game played from the initial position.
+    ///
+    /// Positions are compared rather than moves, so a line is found from any
+    /// of the four first moves and whatever order its moves were played in.
+    /// Returns `None` when the game reached no named line.
+    pub fn identify(&self, moves: &[Move]) -> Option<OpeningMatch> {
+        let game = positions(moves.iter().copied());
+        // openings never pass, so a pass is out of theory too
+        let in_theory = game
+            .iter()
+            .enumerate()
+            .take_while(|&(ply, board)| self.lines.iter().any(|line| line.get(ply) == Some(board)))
+            .count();
+        let mut best: Option<&Opening> = None;
+        let mut best_len = 0;
+        for (opening, line) in OPENINGS.iter().zip(&self.lines) {
+            let len = line.len();
+            if len > best_len && len <= game.len() && line.last() == Some(&game[len - 1]) {
+                best = Some(opening);
+                best_len = len;
+            }
+        }
+        best.map(|opening| OpeningMatch {
+            name: opening.name,
+            len: best_len,
+            left_theory: if in_theory < moves.len() {
+                Some(in_theory)
+            } else {
+                None
+            },
+        })
+    }
+}
+
+impl Default for OpeningBook {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `OpeningBook::identify` on a book shared by all calls. Without `std` the
+/// book is built on every call, so keep an `OpeningBook` around instead.
+pub fn identify(moves: &[Move]) -> Option<OpeningMatch> {
+    #[cfg(feature = "std")]
+    {
+        static BOOK: std::sync::OnceLock<OpeningBook> = std::sync::OnceLock::new();
+        BOOK.get_or_init(OpeningBook::new).identify(moves)
+    }
+    #[cfg(not(feature = "std"))]
+    OpeningBook::new().identify(moves)
+}
+
+impl GameRecord {
+    /// `identify` on the moves of this record, `None` if it doesn't start
+    /// from the initial position.
+    pub fn opening(&self) -> Option<OpeningMatch> {
+        if *self.initial_board() != Board::new() {
+            return None;
+        }
+        identify(self.moves())
+    }
+}
@@ -1,56 +1,342 @@
-use std::ops::{Shl, Shr};
+use core::{
+    fmt::Debug,
+    hash::Hash,
+    ops::{BitAnd, BitOr, BitXor, Not, Shl, Shr},
+};
+
+// without std there is no runtime detection, so the choice is made by the
+// target features the crate is compiled with
+#[cfg(all(target_arch = "x86_64", feature = "std"))]
+fn has_avx2() -> bool {
+    is_x86_feature_detected!("avx2")
+}
+#[cfg(all(target_arch = "x86_64", not(feature = "std")))]
+fn has_avx2() -> bool {
+    cfg!(target_feature = "avx2")
+}
 
 pub fn legal_move(player: u64, opponent: u64) -> u64 {
-    let masks = [
-        (1, 0x7e7e7e7e7e7e7e7e), // 左右
-        (7, 0x007e7e7e7e7e7e00), // 右上、左下
-        (8, 0x00ffffffffffff00), // 上下
-        (9, 0x007e7e7e7e7e7e00), // 左上、右下
-    ];
-    let shifts = [Shr::shr, Shl::shl];
-    let mut candidate = 0;
-
-    for (n_shifts, mask) in masks.iter() {
-        let mask = mask & opponent;
-
-        for shift in shifts.iter() {
-            let mut bits = mask & shift(player, n_shifts);
-            for _ in 0..5 {
-                bits |= mask & shift(bits, n_shifts);
-            }
-            candidate |= shift(bits, n_shifts);
+    #[cfg(target_arch = "x86_64")]
+    {
+        if has_avx2() {
+            return unsafe { x86::legal_move_avx2(player, opponent) };
         }
+        unsafe { x86::legal_move_sse2(player, opponent) }
     }
-
-    candidate & !(player | opponent)
+    #[cfg(not(target_arch = "x86_64"))]
+    portable::legal_move(player, opponent)
 }
 pub fn reverse(player: u64, opponent: u64, position: u64) -> u64 {
-    let masks: [(i32, u64); 4] = [
-        (1, 0xfefefefefefefefe),
-        (7, 0x7f7f7f7f7f7f7f00),
-        (8, 0xffffffffffffff00),
-        (9, 0xfefefefefefefe00),
-    ];
-    let shifts = [Shl::shl, Shr::shr];
-    let mut rev = 0;
-
-    for (n_shifts, mut mask) in masks.iter() {
-        for shift in shifts.iter() {
-            let mut r = 0;
-            let mut pos = mask & shift(position, n_shifts);
-            while pos & opponent != 0 {
-                r |= pos;
-                pos = mask & shift(pos, n_shifts);
+    #[cfg(target_arch = "x86_64")]
+    {
+        if has_avx2() {
+            return unsafe { x86::reverse_avx2(player, opponent, position) };
+        }
+        unsafe { x86::reverse_sse2(player, opponent, position) }
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    portable::reverse(player, opponent, position)
+}
+
+// The fast versions below fill all runs of a direction at once with shifts
+// of 1, 2 and 2 steps (Kogge-Stone), which covers the 6 discs a run can have.
+// `mask` is the opponent with the columns a and h removed for every direction
+// but the vertical one, so that no shift wraps around a row.
+mod portable {
+    const INNER: u64 = 0x7e7e7e7e7e7e7e7e;
+
+    fn fill_left(seed: u64, mask: u64, dir: u32) -> u64 {
+        let mut flood = mask & (seed << dir);
+        flood |= mask & (flood << dir);
+        let pre = mask & (mask << dir);
+        flood |= pre & (flood << (dir * 2));
+        flood |= pre & (flood << (dir * 2));
+        flood
+    }
+    fn fill_right(seed: u64, mask: u64, dir: u32) -> u64 {
+        let mut flood = mask & (seed >> dir);
+        flood |= mask & (flood >> dir);
+        let pre = mask & (mask >> dir);
+        flood |= pre & (flood >> (dir * 2));
+        flood |= pre & (flood >> (dir * 2));
+        flood
+    }
+    pub fn legal_move_horizontal(player: u64, opponent: u64) -> u64 {
+        let mask = opponent & INNER;
+        (fill_left(player, mask, 1) << 1) | (fill_right(player, mask, 1) >> 1)
+    }
+    pub fn reverse_horizontal(player: u64, opponent: u64, position: u64) -> u64 {
+        let mask = opponent & INNER;
+        let mut rev = 0;
+        let flood = fill_left(position, mask, 1);
+        if player & (flood << 1) != 0 {
+            rev |= flood;
+        }
+        let flood = fill_right(position, mask, 1);
+        if player & (flood >> 1) != 0 {
+            rev |= flood;
+        }
+        rev
+    }
+    pub fn legal_move(player: u64, opponent: u64) -> u64 {
+        let mut moves = legal_move_horizontal(player, opponent);
+        for &(dir, mask) in [(7, INNER), (8, !0), (9, INNER)].iter() {
+            let mask = opponent & mask;
+            moves |= fill_left(player, mask, dir) << dir;
+            moves |= fill_right(player, mask, dir) >> dir;
+        }
+        moves & !(player | opponent)
+    }
+    pub fn reverse(player: u64, opponent: u64, position: u64) -> u64 {
+        let mut rev = reverse_horizontal(player, opponent, position);
+        for &(dir, mask) in [(7, INNER), (8, !0), (9, INNER)].iter() {
+            let mask = opponent & mask;
+            let flood = fill_left(position, mask, dir);
+            if player & (flood << dir) != 0 {
+                rev |= flood;
+            }
+            let flood = fill_right(position, mask, dir);
+            if player & (flood >> dir) != 0 {
+                rev |= flood;
             }
-            if pos & player != 0 {
-                rev |= r;
+        }
+        rev
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+    use super::portable;
+    use core::arch::x86_64::*;
+
+    const INNER: i64 = 0x7e7e7e7e7e7e7e7e;
+
+    // SSE2 has no per-lane shift counts, so the second lane holds the board
+    // mirrored vertically: a left shift there is a shift toward the other
+    // vertical side of the real board (8 <-> 8, 7 <-> 9). Rows are mirrored
+    // back with a byte swap; the horizontal pair is done on scalars.
+    #[inline]
+    unsafe fn pair(bits: u64) -> __m128i {
+        _mm_set_epi64x(bits.swap_bytes() as i64, bits as i64)
+    }
+    #[inline]
+    unsafe fn unpair(v: __m128i) -> u64 {
+        let lo = _mm_cvtsi128_si64(v) as u64;
+        let hi = _mm_cvtsi128_si64(_mm_unpackhi_epi64(v, v)) as u64;
+        lo | hi.swap_bytes()
+    }
+
+    macro_rules! fill_sse2 {
+        ($gen:expr, $mask:expr, $dir:literal, $dir2:literal) => {{
+            let mut flood = _mm_and_si128($mask, _mm_slli_epi64::<$dir>($gen));
+            flood = _mm_or_si128(flood, _mm_and_si128($mask, _mm_slli_epi64::<$dir>(flood)));
+            let pre = _mm_and_si128($mask, _mm_slli_epi64::<$dir>($mask));
+            flood = _mm_or_si128(flood, _mm_and_si128(pre, _mm_slli_epi64::<$dir2>(flood)));
+            flood = _mm_or_si128(flood, _mm_and_si128(pre, _mm_slli_epi64::<$dir2>(flood)));
+            flood
+        }};
+    }
+
+    #[target_feature(enable = "sse2")]
+    pub unsafe fn legal_move_sse2(player: u64, opponent: u64) -> u64 {
+        let p = pair(player);
+        let o = pair(opponent);
+        let inner = _mm_and_si128(o, _mm_set1_epi64x(INNER));
+        let mut moves = _mm_slli_epi64::<8>(fill_sse2!(p, o, 8, 16));
+        moves = _mm_or_si128(moves, _mm_slli_epi64::<7>(fill_sse2!(p, inner, 7, 14)));
+        moves = _mm_or_si128(moves, _mm_slli_epi64::<9>(fill_sse2!(p, inner, 9, 18)));
+        let moves = unpair(moves) | portable::legal_move_horizontal(player, opponent);
+        moves & !(player | opponent)
+    }
+
+    // keeps the lanes of `flood` whose outflanking square holds a player disc
+    #[inline]
+    unsafe fn outflanked_sse2(flood: __m128i, outflank: __m128i) -> __m128i {
+        let zero32 = _mm_cmpeq_epi32(outflank, _mm_setzero_si128());
+        let zero64 = _mm_and_si128(zero32, _mm_shuffle_epi32::<0b10_11_00_01>(zero32));
+        _mm_andnot_si128(zero64, flood)
+    }
+
+    #[target_feature(enable = "sse2")]
+    pub unsafe fn reverse_sse2(player: u64, opponent: u64, position: u64) -> u64 {
+        let p = pair(player);
+        let o = pair(opponent);
+        let pos = pair(position);
+        let inner = _mm_and_si128(o, _mm_set1_epi64x(INNER));
+        let f8 = fill_sse2!(pos, o, 8, 16);
+        let f7 = fill_sse2!(pos, inner, 7, 14);
+        let f9 = fill_sse2!(pos, inner, 9, 18);
+        let mut rev = outflanked_sse2(f8, _mm_and_si128(p, _mm_slli_epi64::<8>(f8)));
+        rev = _mm_or_si128(
+            rev,
+            outflanked_sse2(f7, _mm_and_si128(p, _mm_slli_epi64::<7>(f7))),
+        );
+        rev = _mm_or_si128(
+            rev,
+            outflanked_sse2(f9, _mm_and_si128(p, _mm_slli_epi64::<9>(f9))),
+        );
+        unpair(rev) | portable::reverse_horizontal(player, opponent, position)
+    }
+
+    // AVX2 shifts each lane by its own count, so one register covers the
+    // four directions and a second one their opposites.
+    #[inline]
+    unsafe fn or_lanes(v: __m256i) -> u64 {
+        let v = _mm_or_si128(_mm256_castsi256_si128(v), _mm256_extracti128_si256::<1>(v));
+        (_mm_cvtsi128_si64(v) | _mm_cvtsi128_si64(_mm_unpackhi_epi64(v, v))) as u64
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub unsafe fn legal_move_avx2(player: u64, opponent: u64) -> u64 {
+        let shift = _mm256_set_epi64x(9, 8, 7, 1);
+        let shift2 = _mm256_add_epi64(shift, shift);
+        let p = _mm256_set1_epi64x(player as i64);
+        let mask = _mm256_and_si256(
+            _mm256_set1_epi64x(opponent as i64),
+            _mm256_set_epi64x(INNER, -1, INNER, INNER),
+        );
+        let mut fl = _mm256_and_si256(mask, _mm256_sllv_epi64(p, shift));
+        let mut fr = _mm256_and_si256(mask, _mm256_srlv_epi64(p, shift));
+        fl = _mm256_or_si256(fl, _mm256_and_si256(mask, _mm256_sllv_epi64(fl, shift)));
+        fr = _mm256_or_si256(fr, _mm256_and_si256(mask, _mm256_srlv_epi64(fr, shift)));
+        let pre_l = _mm256_and_si256(mask, _mm256_sllv_epi64(mask, shift));
+        let pre_r = _mm256_and_si256(mask, _mm256_srlv_epi64(mask, shift));
+        for _ in 0..2 {
+            fl = _mm256_or_si256(fl, _mm256_and_si256(pre_l, _mm256_sllv_epi64(fl, shift2)));
+            fr = _mm256_or_si256(fr, _mm256_and_si256(pre_r, _mm256_srlv_epi64(fr, shift2)));
+        }
+        let moves = _mm256_or_si256(_mm256_sllv_epi64(fl, shift), _mm256_srlv_epi64(fr, shift));
+        or_lanes(moves) & !(player | opponent)
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub unsafe fn reverse_avx2(player: u64, opponent: u64, position: u64) -> u64 {
+        let shift = _mm256_set_epi64x(9, 8, 7, 1);
+        let shift2 = _mm256_add_epi64(shift, shift);
+        let p = _mm256_set1_epi64x(player as i64);
+        let pos = _mm256_set1_epi64x(position as i64);
+        let mask = _mm256_and_si256(
+            _mm256_set1_epi64x(opponent as i64),
+            _mm256_set_epi64x(INNER, -1, INNER, INNER),
+        );
+        let mut fl = _mm256_and_si256(mask, _mm256_sllv_epi64(pos, shift));
+        let mut fr = _mm256_and_si256(mask, _mm256_srlv_epi64(pos, shift));
+        fl = _mm256_or_si256(fl, _mm256_and_si256(mask, _mm256_sllv_epi64(fl, shift)));
+        fr = _mm256_or_si256(fr, _mm256_and_si256(mask, _mm256_srlv_epi64(fr, shift)));
+        let pre_l = _mm256_and_si256(mask, _mm256_sllv_epi64(mask, shift));
+        let pre_r = _mm256_and_si256(mask, _mm256_srlv_epi64(mask, shift));
+        for _ in 0..2 {
+            fl = _mm256_or_si256(fl, _mm256_and_si256(pre_l, _mm256_sllv_epi64(fl, shift2)));
+            fr = _mm256_or_si256(fr, _mm256_and_si256(pre_r, _mm256_srlv_epi64(fr, shift2)));
+        }
+        let zero = _mm256_setzero_si256();
+        let out_l = _mm256_and_si256(p, _mm256_sllv_epi64(fl, shift));
+        let out_r = _mm256_and_si256(p, _mm256_srlv_epi64(fr, shift));
+        fl = _mm256_andnot_si256(_mm256_cmpeq_epi64(out_l, zero), fl);
+        fr = _mm256_andnot_si256(_mm256_cmpeq_epi64(out_r, zero), fr);
+        or_lanes(_mm256_or_si256(fl, fr))
+    }
+}
+
+/// Every implementation behind `legal_move` and `reverse`, for the tests and
+/// benchmarks that compare them. Not part of the API.
+#[doc(hidden)]
+pub mod backends {
+    use core::ops::{Shl, Shr};
+
+    pub type LegalMove = fn(u64, u64) -> u64;
+    pub type Reverse = fn(u64, u64, u64) -> u64;
+
+    // the straightforward versions the others are checked against
+    pub fn legal_move_reference(player: u64, opponent: u64) -> u64 {
+        let masks = [
+            (1, 0x7e7e7e7e7e7e7e7e), // 左右
+            (7, 0x007e7e7e7e7e7e00), // 右上、左下
+            (8, 0x00ffffffffffff00), // 上下
+            (9, 0x007e7e7e7e7e7e00), // 左上、右下
+        ];
+        let shifts = [Shr::shr, Shl::shl];
+        let mut candidate = 0;
+
+        for (n_shifts, mask) in masks.iter() {
+            let mask = mask & opponent;
+
+            for shift in shifts.iter() {
+                let mut bits = mask & shift(player, n_shifts);
+                for _ in 0..5 {
+                    bits |= mask & shift(bits, n_shifts);
+                }
+                candidate |= shift(bits, n_shifts);
             }
+        }
+
+        candidate & !(player | opponent)
+    }
+    pub fn reverse_reference(player: u64, opponent: u64, position: u64) -> u64 {
+        let masks: [(i32, u64); 4] = [
+            (1, 0xfefefefefefefefe),
+            (7, 0x7f7f7f7f7f7f7f00),
+            (8, 0xffffffffffffff00),
+            (9, 0xfefefefefefefe00),
+        ];
+        let shifts = [Shl::shl, Shr::shr];
+        let mut rev = 0;
 
-            mask >>= n_shifts;
+        for (n_shifts, mut mask) in masks.iter() {
+            for shift in shifts.iter() {
+                let mut r = 0;
+                let mut pos = mask & shift(position, n_shifts);
+                while pos & opponent != 0 {
+                    r |= pos;
+                    pos = mask & shift(pos, n_shifts);
+                }
+                if pos & player != 0 {
+                    rev |= r;
+                }
+
+                mask >>= n_shifts;
+            }
         }
+
+        rev
     }
 
-    rev
+    /// The backends this CPU can run, named, the reference first.
+    pub fn available() -> impl Iterator<Item = (&'static str, LegalMove, Reverse)> {
+        #[cfg(target_arch = "x86_64")]
+        let simd: [Option<(&'static str, LegalMove, Reverse)>; 2] = [
+            // SSE2 is part of x86_64
+            Some((
+                "sse2",
+                |p, o| unsafe { super::x86::legal_move_sse2(p, o) },
+                |p, o, pos| unsafe { super::x86::reverse_sse2(p, o, pos) },
+            )),
+            if super::has_avx2() {
+                Some((
+                    "avx2",
+                    |p, o| unsafe { super::x86::legal_move_avx2(p, o) },
+                    |p, o, pos| unsafe { super::x86::reverse_avx2(p, o, pos) },
+                ))
+            } else {
+                None
+            },
+        ];
+        #[cfg(not(target_arch = "x86_64"))]
+        let simd: [Option<(&'static str, LegalMove, Reverse)>; 0] = [];
+        let scalar = [
+            (
+                "reference",
+                legal_move_reference as LegalMove,
+                reverse_reference as Reverse,
+            ),
+            (
+                "portable",
+                super::portable::legal_move,
+                super::portable::reverse,
+            ),
+        ];
+        IntoIterator::into_iter(scalar).chain(IntoIterator::into_iter(simd).flatten())
+    }
 }
 
 pub fn put(player: u64, opponent: u64, position: u64) -> (u64, u64) {
@@ -60,3 +346,157 @@ pub fn put(player: u64, opponent: u64, position: u64) -> (u64, u64) {
 
     (player, opponent)
 }
+
+pub trait Bitboard:
+    Copy
+    + Eq
+    + Ord
+    + Hash
+    + Default
+    + Debug
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+    + Not<Output = Self>
+    + Shl<usize, Output = Self>
+    + Shr<usize, Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    fn count_ones(self) -> u32;
+    fn leading_zeros(self) -> u32;
+    fn wrapping_neg(self) -> Self;
+}
+
+macro_rules! impl_bitboard {
+    ($($t:ty),*) => {
+        $(
+            impl Bitboard for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                fn count_ones(self) -> u32 {
+                    <$t>::count_ones(self)
+                }
+                fn leading_zeros(self) -> u32 {
+                    <$t>::leading_zeros(self)
+                }
+                fn wrapping_neg(self) -> Self {
+                    <$t>::wrapping_neg(self)
+                }
+            }
+        )*
+    };
+}
+impl_bitboard!(u64, u128);
+
+/// Shape of an N x N board.
+///
+/// Squares are stored row by row with a1 in the highest bit used, the same
+/// layout as the 8x8 functions above.
+pub trait Geometry {
+    const SIZE: usize;
+    type Bits: Bitboard;
+    const FULL: Self::Bits;
+    // squares outside column a and outside the last column
+    const NOT_A: Self::Bits;
+    const NOT_H: Self::Bits;
+
+    fn square(x: usize, y: usize) -> Self::Bits {
+        Self::Bits::ONE << (Self::SIZE * Self::SIZE - 1 - (y * Self::SIZE + x))
+    }
+    fn initial() -> (Self::Bits, Self::Bits) {
+        let h = Self::SIZE / 2;
+        (
+            Self::square(h, h - 1) | Self::square(h - 1, h),
+            Self::square(h - 1, h - 1) | Self::square(h, h),
+        )
+    }
+    // (shift, mask after Shl, mask after Shr)
+    fn directions() -> [(usize, Self::Bits, Self::Bits); 4] {
+        let n = Self::SIZE;
+        [
+            (1, Self::NOT_H, Self::NOT_A),
+            (n - 1, Self::NOT_A, Self::NOT_H),
+            (n, Self::FULL, Self::FULL),
+            (n + 1, Self::NOT_H, Self::NOT_A),
+        ]
+    }
+    fn legal_move(player: Self::Bits, opponent: Self::Bits) -> Self::Bits {
+        let shifts = [Shl::shl, Shr::shr];
+        let mut candidate = Self::Bits::ZERO;
+        for (n_shifts, shl_mask, shr_mask) in Self::directions().iter() {
+            for (shift, mask) in shifts.iter().zip([shl_mask, shr_mask].iter()) {
+                let opponent = **mask & opponent;
+                let mut bits = opponent & shift(player, *n_shifts);
+                for _ in 0..Self::SIZE - 3 {
+                    bits = bits | (opponent & shift(bits, *n_shifts));
+                }
+                candidate = candidate | (**mask & shift(bits, *n_shifts));
+            }
+        }
+        candidate & !(player | opponent)
+    }
+    fn reverse(player: Self::Bits, opponent: Self::Bits, position: Self::Bits) -> Self::Bits {
+        let shifts = [Shl::shl, Shr::shr];
+        let mut rev = Self::Bits::ZERO;
+        for (n_shifts, shl_mask, shr_mask) in Self::directions().iter() {
+            for (shift, mask) in shifts.iter().zip([shl_mask, shr_mask].iter()) {
+                let mut r = Self::Bits::ZERO;
+                let mut pos = **mask & shift(position, *n_shifts);
+                while pos & opponent != Self::Bits::ZERO {
+                    r = r | pos;
+                    pos = **mask & shift(pos, *n_shifts);
+                }
+                if pos & player != Self::Bits::ZERO {
+                    rev = rev | r;
+                }
+            }
+        }
+        rev
+    }
+}
+
+pub struct Size<const N: usize>;
+
+// squares of an n x n board except those in column `skip` (none if skip >= n)
+const fn column_mask(n: usize, skip: usize) -> u128 {
+    let mut mask = 0;
+    let mut i = 0;
+    while i < n * n {
+        if i % n != skip {
+            mask |= 1 << (n * n - 1 - i);
+        }
+        i += 1;
+    }
+    mask
+}
+
+macro_rules! impl_geometry {
+    ($n:expr, $t:ty) => {
+        impl Geometry for Size<$n> {
+            const SIZE: usize = $n;
+            type Bits = $t;
+            const FULL: $t = column_mask($n, $n) as $t;
+            const NOT_A: $t = column_mask($n, 0) as $t;
+            const NOT_H: $t = column_mask($n, $n - 1) as $t;
+        }
+    };
+}
+impl_geometry!(6, u64);
+impl_geometry!(10, u128);
+
+// the 8x8 board keeps its hand-written move generator
+impl Geometry for Size<8> {
+    const SIZE: usize = 8;
+    type Bits = u64;
+    const FULL: u64 = 0xffffffffffffffff;
+    const NOT_A: u64 = 0x7f7f7f7f7f7f7f7f;
+    const NOT_H: u64 = 0xfefefefefefefefe;
+
+    fn legal_move(player: u64, opponent: u64) -> u64 {
+        legal_move(player, opponent)
+    }
+    fn reverse(player: u64, opponent: u64, position: u64) -> u64 {
+        reverse(player, opponent, position)
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{
+    othello_logic::{Geometry, Size},
+    SizedBoard, Stone, StoneCount,
+};
+
+/// How a finished game ended.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum GameEnd {
+    FullBoard,
+    /// Neither side can move with empty squares left, including a wipeout.
+    MutualPass,
+}
+
+/// Final score of a finished game.
+///
+/// `black` and `white` follow the official rule: empty squares go to the
+/// winner and are split evenly on a draw, so they always add up to the number
+/// of squares.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameOutcome {
+    pub winner: Option<Stone>,
+    pub black: usize,
+    pub white: usize,
+    pub end: GameEnd,
+}
+
+impl GameOutcome {
+    /// Returns `None` while either side still has a legal move.
+    pub fn from_board<const N: usize>(board: &SizedBoard<N>) -> Option<Self>
+    where
+        Size<N>: Geometry,
+    {
+        if !board.finished() {
+            return None;
+        }
+        let StoneCount { black, white } = board.count_stone();
+        let empty = N * N - black - white;
+        let end = if empty == 0 {
+            GameEnd::FullBoard
+        } else {
+            GameEnd::MutualPass
+        };
+        let (winner, black, white) = match black.cmp(&white) {
+            core::cmp::Ordering::Greater => (Some(Stone::Black), black + empty, white),
+            core::cmp::Ordering::Less => (Some(Stone::White), black, white + empty),
+            core::cmp::Ordering::Equal => (None, black + empty / 2, white + empty / 2),
+        };
+        Some(GameOutcome {
+            winner,
+            black,
+            white,
+            end,
+        })
+    }
+    /// Black's score minus white's.
+    pub fn differential(&self) -> i32 {
+        self.black as i32 - self.white as i32
+    }
+    pub fn differential_for(&self, stone: Stone) -> i32 {
+        if stone == Stone::Black {
+            self.differential()
+        } else {
+            -self.differential()
+        }
+    }
+    /// 1 for a win, 0 for a draw and -1 for a loss of `stone`.
+    pub fn result_for(&self, stone: Stone) -> i8 {
+        match self.winner {
+            Some(winner) if winner == stone => 1,
+            Some(_) => -1,
+            None => 0,
+        }
+    }
+}
+
+impl<const N: usize> SizedBoard<N>
+where
+    Size<N>: Geometry,
+{
+    pub fn outcome(&self) -> Option<GameOutcome> {
+        GameOutcome::from_board(self)
+    }
+}
@@ -0,0 +1,123 @@
+#[cfg(feature = "std")]
+use crate::{
+    othello_logic::{legal_move, put},
+    Board, Stone, SIZE,
+};
+use crate::{
+    othello_logic::{Bitboard, Geometry, Size},
+    SizedBoard,
+};
+#[cfg(feature = "std")]
+use fxhash::FxHashMap;
+
+/// Published leaf counts from the initial position, indexed by depth.
+///
+/// A pass counts as a ply and a game that ends early counts as one leaf.
+pub const PERFT_COUNTS: [u64; 15] = [
+    1,
+    4,
+    12,
+    56,
+    244,
+    1396,
+    8200,
+    55092,
+    390216,
+    3005288,
+    24571284,
+    212258800,
+    1939886636,
+    18429641748,
+    184042084512,
+];
+
+fn perft_bits<const N: usize>(
+    player: <Size<N> as Geometry>::Bits,
+    opponent: <Size<N> as Geometry>::Bits,
+    depth: usize,
+    passed: bool,
+) -> u64
+where
+    Size<N>: Geometry,
+{
+    if depth == 0 {
+        return 1;
+    }
+    let zero = <Size<N> as Geometry>::Bits::ZERO;
+    let mut moves = Size::<N>::legal_move(player, opponent);
+    if moves == zero {
+        if passed {
+            return 1;
+        }
+        return perft_bits::<N>(opponent, player, depth - 1, true);
+    }
+    if depth == 1 {
+        return moves.count_ones() as u64;
+    }
+    let mut count = 0;
+    while moves != zero {
+        let pos = moves & moves.wrapping_neg();
+        moves = moves ^ pos;
+        let rev = Size::<N>::reverse(player, opponent, pos);
+        let (player, opponent) = (player ^ (pos | rev), opponent ^ rev);
+        count += perft_bits::<N>(opponent, player, depth - 1, false);
+    }
+    count
+}
+
+/// Counts the move paths of length `depth` from `board`.
+pub fn perft<const N: usize>(board: &SizedBoard<N>, depth: usize) -> u64
+where
+    Size<N>: Geometry,
+{
+    let (player, opponent) = board.players();
+    perft_bits::<N>(player, opponent, depth, false)
+}
+
+#[cfg(feature = "std")]
+type PerftCache = FxHashMap<(Board, usize, bool), u64>;
+
+#[cfg(feature = "std")]
+fn perft_cached(board: Board, depth: usize, passed: bool, cache: &mut PerftCache) -> u64 {
+    if depth <= 2 {
+        let (player, opponent) = board.players();
+        return perft_bits::<SIZE>(player, opponent, depth, passed);
+    }
+    // symmetric positions have the same count, so they share one entry
+    let key = (board.canonical().0, depth, passed);
+    if let Some(&count) = cache.get(&key) {
+        return count;
+    }
+    let (player, opponent) = board.players();
+    let mut moves = legal_move(player, opponent);
+    let count = if moves == 0 {
+        if passed {
+            1
+        } else {
+            let next = Board::from_raw(board.black(), board.white(), board.turn().reverse());
+            perft_cached(next, depth - 1, true, cache)
+        }
+    } else {
+        let mut count = 0;
+        while moves != 0 {
+            let pos = moves & moves.wrapping_neg();
+            moves ^= pos;
+            let (player, opponent) = put(player, opponent, pos);
+            let next = if board.turn() == Stone::Black {
+                Board::from_raw(player, opponent, Stone::White)
+            } else {
+                Board::from_raw(opponent, player, Stone::Black)
+            };
+            count += perft_cached(next, depth - 1, false, cache);
+        }
+        count
+    };
+    cache.insert(key, count);
+    count
+}
+
+/// Same count as `perft`, merging transpositions and symmetric positions.
+#[cfg(feature = "std")]
+pub fn perft_symmetric(board: &Board, depth: usize) -> u64 {
+    perft_cached(*board, depth, false, &mut FxHashMap::default())
+}
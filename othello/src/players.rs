@@ -1,21 +1,32 @@
-use crate::{Board, Position};
-use anyhow::Result;
+use crate::{Board, BoardError, Move};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
-enum PlayerError {
+pub enum PlayerError {
     #[error("not found legal move")]
     NotFoundLegalMove,
+    #[error(transparent)]
+    Board(#[from] BoardError),
+    #[cfg(feature = "alphazero")]
+    #[error(transparent)]
+    Model(#[from] ModelError),
 }
 pub trait Player {
-    fn find_move(&mut self, board: &Board) -> Result<Position>;
+    /// Returns `Move::Pass` when the side to move has to pass.
+    fn find_move(&mut self, board: &Board) -> Result<Move, PlayerError>;
 }
 
 pub mod random;
 pub use random::RandomPlayer;
+#[cfg(feature = "alphazero")]
 pub mod alphazero;
-pub use alphazero::AlphaZeroPlayer;
+#[cfg(feature = "alphazero")]
+pub use alphazero::{AlphaZeroPlayer, ModelError};
 pub mod mcts;
 pub use mcts::MCTSPlayer;
 pub mod greedy;
 pub use greedy::GreedyPlayer;
+pub mod alphabeta;
+pub use alphabeta::{AlphaBetaPlayer, SearchLimit};
+pub mod endgame;
+pub use endgame::EndgamePlayer;
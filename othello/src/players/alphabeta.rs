@@ -0,0 +1,233 @@
+use super::Player;
+use crate::{
+    features::SideFeatures,
+    players::PlayerError,
+    search::{negascout, OrderedMoves},
+    zobrist::{move_delta, side_to_move_key},
+    Board, Move, Position,
+};
+use fxhash::FxHashMap;
+
+/// Score of a finished game that is won, before adding the disc differential.
+///
+/// Evaluation functions should stay well inside `±WIN_SCORE` so that a
+/// won ending always scores above any unfinished position.
+pub const WIN_SCORE: i32 = 1_000_000;
+const INFINITY: i32 = i32::MAX;
+
+/// Scores a board from the side to move's point of view.
+pub type Evaluator = fn(&Board) -> i32;
+
+/// Default evaluation built on `Board::features`.
+pub fn evaluate(board: &Board) -> i32 {
+    fn side(s: SideFeatures) -> i32 {
+        100 * s.corners as i32
+            + 30 * s.stable as i32
+            + 20 * s.mobility as i32
+            + 10 * s.potential_mobility as i32
+            - 10 * s.frontier as i32
+            - 40 * s.x_squares as i32
+            - 10 * s.c_squares as i32
+    }
+    let features = board.features();
+    side(features.player) - side(features.opponent)
+}
+
+/// When `AlphaBetaPlayer` stops deepening.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SearchLimit {
+    /// Search exactly this many moves deep. Passes are free and don't count.
+    Depth(u32),
+    /// Deepen until this many nodes are visited, keeping the move of the
+    /// last iteration that completed.
+    Nodes(u64),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Bound {
+    Exact,
+    Lower,
+    Upper,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Entry {
+    depth: u32,
+    score: i32,
+    bound: Bound,
+    best: Option<Position>,
+}
+
+/// Negascout with iterative deepening and a transposition table keyed by
+/// the zobrist hash. Moves are tried best move of the previous iteration
+/// first, then by how few replies they leave the opponent.
+pub struct AlphaBetaPlayer<E = Evaluator> {
+    eval: E,
+    limit: SearchLimit,
+    table: FxHashMap<u64, Entry>,
+    nodes: u64,
+    aborted: bool,
+}
+
+impl AlphaBetaPlayer {
+    pub fn new(limit: SearchLimit) -> Self {
+        Self::with_eval(limit, evaluate as Evaluator)
+    }
+}
+
+impl<E: FnMut(&Board) -> i32> AlphaBetaPlayer<E> {
+    pub fn with_eval(limit: SearchLimit, eval: E) -> Self {
+        AlphaBetaPlayer {
+            eval,
+            limit,
+            table: FxHashMap::default(),
+            nodes: 0,
+            aborted: false,
+        }
+    }
+    /// Nodes visited by the last `search`.
+    pub fn nodes(&self) -> u64 {
+        self.nodes
+    }
+    /// The best move for the side to move and its score, or `None` when it
+    /// has no legal move.
+    pub fn search(&mut self, board: &Board) -> Option<(Position, i32)> {
+        if board.get_legal_moves().is_empty() {
+            return None;
+        }
+        self.table.clear();
+        self.nodes = 0;
+        self.aborted = false;
+        let max_depth = match self.limit {
+            SearchLimit::Depth(depth) => depth.max(1),
+            SearchLimit::Nodes(_) => (!(board.black() | board.white())).count_ones(),
+        };
+        let hash = board.zobrist();
+        let mut board = *board;
+        let mut best = None;
+        for depth in 1..=max_depth {
+            let score = self.negascout(&mut board, hash, depth, -INFINITY, INFINITY);
+            if self.aborted {
+                break;
+            }
+            let pos = self.table[&hash].best.expect("root has a legal move");
+            best = Some((pos, score));
+            if score.abs() >= WIN_SCORE {
+                break;
+            }
+        }
+        // a node limit too small for even one iteration still gets a move
+        best.or_else(|| board.get_legal_moves().nth(0).map(|pos| (pos, 0)))
+    }
+
+    fn out_of_nodes(&self) -> bool {
+        match self.limit {
+            SearchLimit::Nodes(limit) => self.nodes > limit,
+            SearchLimit::Depth(_) => false,
+        }
+    }
+
+    fn negascout(
+        &mut self,
+        board: &mut Board,
+        hash: u64,
+        depth: u32,
+        alpha: i32,
+        beta: i32,
+    ) -> i32 {
+        self.nodes += 1;
+        if self.out_of_nodes() {
+            self.aborted = true;
+            return 0;
+        }
+        let moves = board.get_legal_moves();
+        if moves.is_empty() {
+            if let Some(outcome) = board.outcome() {
+                let diff = outcome.differential_for(board.turn());
+                return diff.signum() * WIN_SCORE + diff;
+            }
+            let undo = board.make_move(Move::Pass);
+            let score = -self.negascout(board, hash ^ side_to_move_key(), depth, -beta, -alpha);
+            board.unmake_move(undo);
+            return score;
+        }
+        if depth == 0 {
+            return (self.eval)(board);
+        }
+
+        let mut hint = None;
+        if let Some(entry) = self.table.get(&hash) {
+            if entry.depth >= depth {
+                match entry.bound {
+                    Bound::Exact => return entry.score,
+                    Bound::Lower if entry.score >= beta => return entry.score,
+                    Bound::Upper if entry.score <= alpha => return entry.score,
+                    _ => {}
+                }
+            }
+            hint = entry.best;
+        }
+
+        let ordered = OrderedMoves::new(moves.0, |pos| {
+            if Some(Position(pos)) == hint {
+                0
+            } else {
+                let undo = board.make_move(Move::Play(Position(pos)));
+                let replies = board.get_legal_moves().count() as i32;
+                board.unmake_move(undo);
+                replies + 1
+            }
+        });
+        let result = negascout(&ordered, alpha, beta, |pos, alpha, beta| {
+            let pos = Position(pos);
+            let undo = board.make_move(Move::Play(pos));
+            let child = hash ^ move_delta(undo.turn, pos, undo.flipped) ^ side_to_move_key();
+            let score = -self.negascout(board, child, depth - 1, -beta, -alpha);
+            board.unmake_move(undo);
+            if self.aborted {
+                None
+            } else {
+                Some(score)
+            }
+        });
+        let (best_score, best_move) = match result {
+            Some((score, pos)) => (score, Position(pos)),
+            None => return 0,
+        };
+
+        let bound = if best_score <= alpha {
+            Bound::Upper
+        } else if best_score >= beta {
+            Bound::Lower
+        } else {
+            Bound::Exact
+        };
+        self.table.insert(
+            hash,
+            Entry {
+                depth,
+                score: best_score,
+                bound,
+                best: Some(best_move),
+            },
+        );
+        best_score
+    }
+}
+
+impl Default for AlphaBetaPlayer {
+    fn default() -> Self {
+        Self::new(SearchLimit::Depth(6))
+    }
+}
+
+impl<E: FnMut(&Board) -> i32> Player for AlphaBetaPlayer<E> {
+    fn find_move(&mut self, board: &Board) -> Result<Move, PlayerError> {
+        if board.must_pass() {
+            return Ok(Move::Pass);
+        }
+        self.search(board)
+            .map(|(pos, _)| Move::Play(pos))
+            .ok_or(PlayerError::NotFoundLegalMove)
+    }
+}
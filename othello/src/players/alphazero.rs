@@ -1,75 +1,81 @@
+#[cfg(feature = "embedded-model")]
 use std::io::BufReader;
 
 use super::Player;
 use crate::{
+    players::PlayerError,
     utils::{create_board_tensor, game_result},
-    Board, Position, Positions, Stone, SIZE, UPPER_LEFT,
+    Board, Move, Position, Positions, Stone, SIZE, UPPER_LEFT,
 };
-use anyhow::Result;
 use fxhash::FxHashMap;
 use rand::prelude::*;
-use rand_distr::Dirichlet;
+use rand_distr::{Dirichlet, DirichletError};
+use thiserror::Error;
 use tract_onnx::{prelude::*, tract_hir::tract_ndarray::Array1};
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+#[derive(Debug, Error)]
+pub enum ModelError {
+    #[error("failed to load model: {0}")]
+    Load(BoxError),
+    #[error("failed to run model: {0}")]
+    Inference(BoxError),
+    #[error("failed to sample exploration noise: {0}")]
+    Noise(#[from] DirichletError),
+}
+
+fn load_model(model: TractResult<InferenceModel>) -> Result<Model, ModelError> {
+    model
+        .and_then(|model| {
+            model
+                .with_input_fact(
+                    0,
+                    InferenceFact::dt_shape(f32::datum_type(), tvec!(1, 2, 8, 8)),
+                )?
+                .into_optimized()?
+                .into_runnable()
+        })
+        .map_err(|e| ModelError::Load(e.into()))
+}
+
 pub struct AlphaZeroPlayer {
     pub mcts: MCTS,
 }
 impl AlphaZeroPlayer {
-    pub fn new(num_simulation: usize) -> Self {
+    #[cfg(feature = "embedded-model")]
+    pub fn new(num_simulation: usize) -> Result<Self, ModelError> {
         let onxx_model = include_bytes!("nn_model/model.onnx");
-        let model = tract_onnx::onnx()
-            .model_for_read(&mut BufReader::new(&onxx_model[..]))
-            .unwrap()
-            .with_input_fact(
-                0,
-                InferenceFact::dt_shape(f32::datum_type(), tvec!(1, 2, 8, 8)),
-            )
-            .unwrap()
-            .into_optimized()
-            .unwrap()
-            .into_runnable()
-            .unwrap();
+        let model =
+            load_model(tract_onnx::onnx().model_for_read(&mut BufReader::new(&onxx_model[..])))?;
         let mcts = MCTS::new(model, 1.0, num_simulation);
-        AlphaZeroPlayer { mcts }
+        Ok(AlphaZeroPlayer { mcts })
     }
-    pub fn new_from_model_path(model_path: &str, num_simulation: usize) -> Self {
-        let model = tract_onnx::onnx()
-            .model_for_path(model_path)
-            .unwrap()
-            .with_input_fact(
-                0,
-                InferenceFact::dt_shape(f32::datum_type(), tvec!(1, 2, 8, 8)),
-            )
-            .unwrap()
-            .into_optimized()
-            .unwrap()
-            .into_runnable()
-            .unwrap();
+    pub fn new_from_model_path(
+        model_path: &str,
+        num_simulation: usize,
+    ) -> Result<Self, ModelError> {
+        let model = load_model(tract_onnx::onnx().model_for_path(model_path))?;
         let mcts = MCTS::new(model, 1.0, num_simulation);
-        AlphaZeroPlayer { mcts }
+        Ok(AlphaZeroPlayer { mcts })
     }
 }
 
+#[cfg(feature = "embedded-model")]
 impl Default for AlphaZeroPlayer {
     fn default() -> Self {
-        let onxx_model = include_bytes!("nn_model/model.onnx");
-        let model = tract_onnx::onnx()
-            .model_for_read(&mut BufReader::new(&onxx_model[..]))
-            .unwrap()
-            .with_input_fact(
-                0,
-                InferenceFact::dt_shape(f32::datum_type(), tvec!(1, 2, 8, 8)),
-            )
-            .unwrap()
-            .into_optimized()
-            .unwrap()
-            .into_runnable()
-            .unwrap();
-        let mcts = MCTS::new(model, 1.0, 5000);
-        AlphaZeroPlayer { mcts }
+        // only fails if the model file bundled with the crate is broken
+        Self::new(5000).expect("failed to load the embedded model")
     }
 }
 impl Player for AlphaZeroPlayer {
-    fn find_move(&mut self, board: &Board) -> Result<Position> {
+    fn find_move(&mut self, board: &Board) -> Result<Move, PlayerError> {
+        if board.must_pass() {
+            return Ok(Move::Pass);
+        }
+        if board.finished() {
+            return Err(PlayerError::NotFoundLegalMove);
+        }
         let ret = self.mcts.search(*board)?;
         // for i in 0..8 {
         //     eprintln!("{:?}", &ret[8*i..8*i+8]);
@@ -81,25 +87,22 @@ impl Player for AlphaZeroPlayer {
             .unwrap()
             .0;
         // eprintln!("{} {}", idx / SIZE, idx % SIZE);
-        Ok(Position(UPPER_LEFT >> idx))
+        Ok(Move::Play(Position(UPPER_LEFT >> idx)))
     }
 }
 
 fn legal_move_to_array(postions: Positions) -> Array1<f32> {
     let mut arr = Array1::zeros(SIZE * SIZE);
-    for i in 0..SIZE * SIZE {
-        let pos = UPPER_LEFT >> i;
-        if postions.0 & pos != 0 {
-            arr[i] = 1f32;
-        }
+    for pos in postions {
+        arr[pos.to_idx()] = 1f32;
     }
     arr
 }
 fn get_state(board: &Board) -> (u64, u64) {
-    if board.turn == Stone::Black {
-        (board.black, board.white)
+    if board.turn() == Stone::Black {
+        (board.black(), board.white())
     } else {
-        (board.white, board.black)
+        (board.white(), board.black())
     }
 }
 type Model = SimplePlan<TypedFact, Box<dyn TypedOp>, Graph<TypedFact, Box<dyn TypedOp>>>;
@@ -143,7 +146,18 @@ impl MCTS {
         self.Ns = FxHashMap::default();
         self.Ps = FxHashMap::default();
     }
-    pub fn search(&mut self, board: Board) -> Result<Vec<f32>> {
+    // (policy, value) from the network for the side to move
+    fn evaluate(&self, board: &Board) -> Result<(Policy, f32), BoxError> {
+        let input = create_board_tensor(board).into_shape(&[1, 2, SIZE, SIZE])?;
+        let output = self.model.run(tvec![input])?;
+        let policy = output[0]
+            .to_array_view::<f32>()?
+            .to_shape(SIZE * SIZE)?
+            .into_owned();
+        let v = *output[1].to_scalar::<f32>()?;
+        Ok((policy, v))
+    }
+    pub fn search(&mut self, board: Board) -> Result<Vec<f32>, PlayerError> {
         let _ = self._search(board)?;
         let state = get_state(&board);
         let eps = self.eps; // TODO selfの借用が回避できない
@@ -159,7 +173,8 @@ impl MCTS {
                             .flatten()
                             .map(|b| (*b as u8) as f32 * alpha + f32::EPSILON)
                             .collect::<Vec<f32>>(),
-                    )?
+                    )
+                    .map_err(ModelError::from)?
                     .sample(&mut self.rng),
                 )
                 .mapv(|x| x * eps);
@@ -179,15 +194,14 @@ impl MCTS {
         Ok(counts.iter().map(|x| *x as f32 / sum).collect())
     }
 
-    fn _search(&mut self, mut board: Board) -> Result<f32> {
-        let player = board.turn;
+    fn _search(&mut self, mut board: Board) -> Result<f32, PlayerError> {
+        let player = board.turn();
         let state = get_state(&board);
         Ok(if let Some(p) = self.Ps.get(&state) {
             let best = board
                 .get_legal_moves()
-                .to_position_list()
                 .iter()
-                .map(|&a| {
+                .map(|a| {
                     (
                         if let Some(q) = self.Qsa.get(&(state, a)) {
                             q + self.cpuct
@@ -211,7 +225,7 @@ impl MCTS {
             board.put(best.1)?;
             let v = if board.finished() {
                 game_result(&board, player) as f32
-            } else if player == board.turn {
+            } else if player == board.turn() {
                 self._search(board)?
             } else {
                 -self._search(board)?
@@ -228,13 +242,7 @@ impl MCTS {
             *self.Ns.get_mut(&state).unwrap() += 1;
             v
         } else {
-            let input = create_board_tensor(&board).into_shape(&[1, 2, SIZE, SIZE])?;
-            let output = self.model.run(tvec![input])?;
-            let mut policy = output[0]
-                .to_array_view::<f32>()?
-                .to_shape(SIZE * SIZE)?
-                .into_owned();
-            let v = *output[1].to_scalar::<f32>().unwrap();
+            let (mut policy, v) = self.evaluate(&board).map_err(ModelError::Inference)?;
             let mask = legal_move_to_array(board.get_legal_moves());
             policy *= &mask;
             if policy.sum() <= 0.0 {
@@ -0,0 +1,41 @@
+use super::{AlphaBetaPlayer, Player};
+use crate::{endgame::Solver, players::PlayerError, Board, Move};
+
+/// Plays perfectly once at most `max_empties` squares are left, and uses
+/// `AlphaBetaPlayer` before that.
+pub struct EndgamePlayer {
+    solver: Solver,
+    midgame: AlphaBetaPlayer,
+    max_empties: u32,
+}
+impl EndgamePlayer {
+    pub fn new(max_empties: u32, midgame: AlphaBetaPlayer) -> Self {
+        EndgamePlayer {
+            solver: Solver::new(),
+            midgame,
+            max_empties,
+        }
+    }
+}
+
+impl Default for EndgamePlayer {
+    fn default() -> Self {
+        // about a second per move at most
+        Self::new(18, AlphaBetaPlayer::default())
+    }
+}
+impl Player for EndgamePlayer {
+    fn find_move(&mut self, board: &Board) -> Result<Move, PlayerError> {
+        if board.must_pass() {
+            return Ok(Move::Pass);
+        }
+        let empties = (!(board.black() | board.white())).count_ones();
+        if empties > self.max_empties {
+            return self.midgame.find_move(board);
+        }
+        self.solver
+            .solve(board)
+            .best
+            .ok_or(PlayerError::NotFoundLegalMove)
+    }
+}
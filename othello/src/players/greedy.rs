@@ -1,6 +1,5 @@
 use super::Player;
-use crate::{players::PlayerError, Board, Position, Stone, StoneCount, SIZE, UPPER_LEFT};
-use anyhow::Result;
+use crate::{players::PlayerError, Board, Move, Stone, StoneCount};
 use rand::{rngs::SmallRng, Rng, SeedableRng};
 pub struct GreedyPlayer {
     thred_rng: SmallRng,
@@ -19,39 +18,31 @@ impl Default for GreedyPlayer {
     }
 }
 impl Player for GreedyPlayer {
-    fn find_move(&mut self, board: &Board) -> Result<Position> {
+    fn find_move(&mut self, board: &Board) -> Result<Move, PlayerError> {
+        if board.must_pass() {
+            return Ok(Move::Pass);
+        }
         let legal_moves = board.get_legal_moves();
-        let n = legal_moves.count();
-        if n == 0 {
-            return Err(PlayerError::NotFoundLegalMove.into());
+        if legal_moves.is_empty() {
+            return Err(PlayerError::NotFoundLegalMove);
         }
         if self.thred_rng.gen_bool(self.p) {
             let mut best = None;
             let mut best_num = 0;
-            let turn = board.turn;
-            for p in legal_moves.to_position_list() {
-                let mut tmp = *board;
-                tmp.put(p)?;
+            let turn = board.turn();
+            let mut tmp = *board;
+            for p in legal_moves {
+                let undo = tmp.make_move(Move::Play(p));
                 let StoneCount { black, white } = tmp.count_stone();
+                tmp.unmake_move(undo);
                 let num = if turn == Stone::Black { black } else { white };
                 if best_num < num {
                     best_num = num;
                     best = Some(p);
                 }
             }
-            return Ok(best.unwrap());
-        } else {
-            let mut idx = self.thred_rng.gen_range(0..n);
-            for i in 0..SIZE * SIZE {
-                let pos = UPPER_LEFT >> i;
-                if legal_moves.0 & pos != 0 {
-                    if idx == 0 {
-                        return Ok(Position(pos));
-                    }
-                    idx -= 1;
-                }
-            }
+            return Ok(Move::Play(best.unwrap()));
         }
-        unreachable!()
+        Ok(Move::Play(legal_moves.choose(&mut self.thred_rng).unwrap()))
     }
 }
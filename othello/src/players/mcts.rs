@@ -1,6 +1,7 @@
 use super::Player;
-use crate::{utils::game_result, Board, Position, Stone, SIZE, UPPER_LEFT};
-use anyhow::Result;
+use crate::{
+    players::PlayerError, utils::game_result, Board, Move, Position, Stone, SIZE, UPPER_LEFT,
+};
 use fxhash::FxHashMap;
 
 type State = (u64, u64);
@@ -35,17 +36,17 @@ impl MCTSPlayer {
         self.Nsa = FxHashMap::default();
         self.Ns = FxHashMap::default();
     }
-    pub fn init_search(&mut self, num_simulation: usize, board: Board) -> Result<()> {
+    pub fn init_search(&mut self, num_simulation: usize, board: Board) -> Result<(), PlayerError> {
         for _ in 0..num_simulation {
             let _ = self._search(board)?;
         }
         Ok(())
     }
-    pub fn search(&mut self, board: Board) -> Result<Vec<f32>> {
-        let state = if board.turn == Stone::Black {
-            (board.black, board.white)
+    pub fn search(&mut self, board: Board) -> Result<Vec<f32>, PlayerError> {
+        let state = if board.turn() == Stone::Black {
+            (board.black(), board.white())
         } else {
-            (board.white, board.black)
+            (board.white(), board.black())
         };
         for _ in 0..self.num_simulation {
             let _ = self._search(board)?;
@@ -62,18 +63,17 @@ impl MCTSPlayer {
         Ok(counts.iter().map(|x| *x as f32 / sum).collect())
     }
 
-    fn _search(&mut self, mut board: Board) -> Result<i8> {
-        let player = board.turn;
+    fn _search(&mut self, mut board: Board) -> Result<i8, PlayerError> {
+        let player = board.turn();
         let state = if player == Stone::Black {
-            (board.black, board.white)
+            (board.black(), board.white())
         } else {
-            (board.white, board.black)
+            (board.white(), board.black())
         };
         let best = board
             .get_legal_moves()
-            .to_position_list()
             .iter()
-            .map(|&a| {
+            .map(|a| {
                 let n = *self.Ns.entry(state).or_insert(1) as f32;
                 (
                     *self.Qsa.entry((state, a)).or_insert(1) as f32 / n
@@ -86,7 +86,7 @@ impl MCTSPlayer {
         board.put(best.1)?;
         let v = if board.finished() {
             game_result(&board, player)
-        } else if player == board.turn {
+        } else if player == board.turn() {
             self._search(board)?
         } else {
             -self._search(board)?
@@ -103,7 +103,13 @@ impl MCTSPlayer {
 }
 
 impl Player for MCTSPlayer {
-    fn find_move(&mut self, board: &Board) -> Result<Position> {
+    fn find_move(&mut self, board: &Board) -> Result<Move, PlayerError> {
+        if board.must_pass() {
+            return Ok(Move::Pass);
+        }
+        if board.finished() {
+            return Err(PlayerError::NotFoundLegalMove);
+        }
         let ret = self.search(*board)?;
         let idx = ret
             .iter()
@@ -111,7 +117,7 @@ impl Player for MCTSPlayer {
             .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
             .unwrap()
             .0;
-        Ok(Position(UPPER_LEFT >> idx))
+        Ok(Move::Play(Position(UPPER_LEFT >> idx)))
     }
 }
 impl Default for MCTSPlayer {
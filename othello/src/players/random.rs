@@ -1,7 +1,6 @@
 use super::Player;
-use crate::{players::PlayerError, Board, Position, SIZE, UPPER_LEFT};
-use anyhow::Result;
-use rand::{rngs::SmallRng, Rng, SeedableRng};
+use crate::{players::PlayerError, Board, Move};
+use rand::{rngs::SmallRng, SeedableRng};
 pub struct RandomPlayer {
     thred_rng: SmallRng,
 }
@@ -18,22 +17,14 @@ impl Default for RandomPlayer {
     }
 }
 impl Player for RandomPlayer {
-    fn find_move(&mut self, board: &Board) -> Result<Position> {
-        let legal_moves = board.get_legal_moves();
-        let n = legal_moves.count();
-        if n == 0 {
-            return Err(PlayerError::NotFoundLegalMove.into());
+    fn find_move(&mut self, board: &Board) -> Result<Move, PlayerError> {
+        if board.must_pass() {
+            return Ok(Move::Pass);
         }
-        let mut idx = self.thred_rng.gen_range(0..n);
-        for i in 0..SIZE * SIZE {
-            let pos = UPPER_LEFT >> i;
-            if legal_moves.0 & pos != 0 {
-                if idx == 0 {
-                    return Ok(Position(pos));
-                }
-                idx -= 1;
-            }
-        }
-        unreachable!()
+        board
+            .get_legal_moves()
+            .choose(&mut self.thred_rng)
+            .map(Move::Play)
+            .ok_or(PlayerError::NotFoundLegalMove)
     }
 }
@@ -0,0 +1,68 @@
+// move ordering and the negascout loop shared by `AlphaBetaPlayer` and the
+// endgame `Solver`
+
+// an othello position never has more than 33 legal moves
+const MAX_MOVES: usize = 40;
+
+pub(crate) struct OrderedMoves {
+    moves: [(i32, u64); MAX_MOVES],
+    len: usize,
+}
+
+impl OrderedMoves {
+    /// The squares of `moves`, lowest `key` first.
+    pub(crate) fn new(mut moves: u64, mut key: impl FnMut(u64) -> i32) -> Self {
+        let mut ordered = OrderedMoves {
+            moves: [(0, 0); MAX_MOVES],
+            len: 0,
+        };
+        while moves != 0 {
+            let pos = moves & moves.wrapping_neg();
+            moves ^= pos;
+            ordered.moves[ordered.len] = (key(pos), pos);
+            ordered.len += 1;
+        }
+        ordered.moves[..ordered.len].sort_unstable_by_key(|&(key, _)| key);
+        ordered
+    }
+    fn iter(&self) -> impl Iterator<Item = u64> + '_ {
+        self.moves[..self.len].iter().map(|&(_, pos)| pos)
+    }
+}
+
+/// Fail-soft negascout over `moves`, returning the best score and move.
+///
+/// `search(pos, alpha, beta)` scores playing `pos` for the side to move, or
+/// returns `None` to abort, which aborts this call too.
+pub(crate) fn negascout(
+    moves: &OrderedMoves,
+    mut alpha: i32,
+    beta: i32,
+    mut search: impl FnMut(u64, i32, i32) -> Option<i32>,
+) -> Option<(i32, u64)> {
+    let mut best_score = -i32::MAX;
+    let mut best = 0;
+    for (i, pos) in moves.iter().enumerate() {
+        let score = if i == 0 {
+            search(pos, alpha, beta)?
+        } else {
+            // scores are integers, so a null window proves or refutes the
+            // move and only one that fails high needs the full window
+            let score = search(pos, alpha, alpha + 1)?;
+            if alpha < score && score < beta {
+                search(pos, alpha, beta)?
+            } else {
+                score
+            }
+        };
+        if score > best_score {
+            best_score = score;
+            best = pos;
+        }
+        alpha = alpha.max(score);
+        if alpha >= beta {
+            break;
+        }
+    }
+    Some((best_score, best))
+}
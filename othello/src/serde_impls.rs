@@ -0,0 +1,101 @@
+// `Serialize`/`Deserialize` through the text notations, so JSON stays
+// readable: squares as `"d3"`, moves as `"d3"` or `"pa"`, boards as
+// position strings and GGF games as GGF text.
+
+use alloc::{string::ToString, vec::Vec};
+use core::{fmt, str::FromStr};
+
+use crate::{game::GameRecord, ggf::GgfGame, Board, Move, Position, Positions};
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+macro_rules! impl_via_str {
+    ($ty:ty, $expecting:literal, $to_string:expr) => {
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&$to_string(self))
+            }
+        }
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct StrVisitor;
+                impl<'de> Visitor<'de> for StrVisitor {
+                    type Value = $ty;
+                    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        f.write_str($expecting)
+                    }
+                    fn visit_str<E: de::Error>(self, v: &str) -> Result<$ty, E> {
+                        <$ty>::from_str(v).map_err(E::custom)
+                    }
+                }
+                deserializer.deserialize_str(StrVisitor)
+            }
+        }
+    };
+}
+
+impl_via_str!(Position, "a square such as \"d3\"", ToString::to_string);
+impl_via_str!(Move, "a square or \"pa\"", ToString::to_string);
+// keeps the side to move as written, like `Board::from_str`
+impl_via_str!(Board, "a position string", Board::to_position_string);
+impl_via_str!(GgfGame, "a GGF game", ToString::to_string);
+
+impl Serialize for Positions {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Positions {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct PositionsVisitor;
+        impl<'de> Visitor<'de> for PositionsVisitor {
+            type Value = Positions;
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a list of squares")
+            }
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Positions, A::Error> {
+                let mut bits = 0;
+                while let Some(pos) = seq.next_element::<Position>()? {
+                    bits |= pos.0;
+                }
+                Ok(Positions(bits))
+            }
+        }
+        deserializer.deserialize_seq(PositionsVisitor)
+    }
+}
+
+// a record is stored as its starting position and moves, and replayed on
+// the way back in so that an illegal game can't be deserialized
+#[derive(Serialize, Deserialize)]
+struct GameRecordRepr {
+    board: Board,
+    moves: Vec<Move>,
+    ply: usize,
+}
+
+impl Serialize for GameRecord {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        GameRecordRepr {
+            board: *self.initial_board(),
+            moves: self.moves().to_vec(),
+            ply: self.ply(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for GameRecord {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = GameRecordRepr::deserialize(deserializer)?;
+        let mut record = GameRecord::from_board(repr.board);
+        for mv in repr.moves {
+            record.apply(mv).map_err(de::Error::custom)?;
+        }
+        record.jump(repr.ply).map_err(de::Error::custom)?;
+        Ok(record)
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{Board, Positions, Stone};
+
+const COLUMN_A: u64 = 0x8080808080808080;
+const COLUMN_H: u64 = 0x0101010101010101;
+
+type Step = fn(u64) -> u64;
+
+// the four lines through a square, as a step each way
+pub(crate) const DIRECTIONS: [(Step, Step); 4] = [
+    (east, west),
+    (south, north),
+    (south_east, north_west),
+    (south_west, north_east),
+];
+
+fn east(b: u64) -> u64 {
+    (b >> 1) & !COLUMN_A
+}
+fn west(b: u64) -> u64 {
+    (b << 1) & !COLUMN_H
+}
+fn south(b: u64) -> u64 {
+    b >> 8
+}
+fn north(b: u64) -> u64 {
+    b << 8
+}
+fn south_east(b: u64) -> u64 {
+    (b >> 9) & !COLUMN_A
+}
+fn north_west(b: u64) -> u64 {
+    (b << 9) & !COLUMN_H
+}
+fn south_west(b: u64) -> u64 {
+    (b >> 7) & !COLUMN_H
+}
+fn north_east(b: u64) -> u64 {
+    (b << 7) & !COLUMN_A
+}
+
+// squares from which `filled` runs up to the edge, in the direction of the
+// neighbour that `back` moves onto each square
+fn filled_ray(filled: u64, back: Step) -> u64 {
+    let edge = !back(!0);
+    let mut ray = filled;
+    for _ in 0..7 {
+        ray = filled & (edge | back(ray));
+    }
+    ray
+}
+
+/// Discs of `player` that can't be flipped for the rest of the game.
+///
+/// A disc is stable when, along each of the four lines through it, the line
+/// is full or the disc is next to the edge or to a stable disc of its own
+/// color. The set grows from the corners until nothing changes.
+pub fn stable_discs(player: u64, opponent: u64) -> u64 {
+    let filled = player | opponent;
+    let mut full = [0; 4];
+    let mut edge = [0; 4];
+    for (i, &(step, back)) in DIRECTIONS.iter().enumerate() {
+        full[i] = filled_ray(filled, back) & filled_ray(filled, step);
+        // no neighbour on one side or the other
+        edge[i] = !back(!0) | !step(!0);
+    }
+    let mut stable = 0;
+    loop {
+        let mut next = player;
+        for (i, &(step, back)) in DIRECTIONS.iter().enumerate() {
+            next &= full[i] | edge[i] | back(stable) | step(stable);
+        }
+        if next == stable {
+            return stable;
+        }
+        stable = next;
+    }
+}
+
+impl Board {
+    pub fn stable_discs(&self, stone: Stone) -> Positions {
+        Positions(if stone == Stone::Black {
+            stable_discs(self.black(), self.white())
+        } else {
+            stable_discs(self.white(), self.black())
+        })
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{Board, Move, Position, Positions};
+
+/// The eight symmetries of the square board.
+///
+/// Rotations are clockwise. `FlipDiagonal` mirrors along a1-h8 and
+/// `FlipAntiDiagonal` along h1-a8.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Symmetry {
+    Identity,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    FlipVertical,
+    FlipHorizontal,
+    FlipDiagonal,
+    FlipAntiDiagonal,
+}
+
+impl Symmetry {
+    pub const ALL: [Symmetry; 8] = [
+        Symmetry::Identity,
+        Symmetry::Rotate90,
+        Symmetry::Rotate180,
+        Symmetry::Rotate270,
+        Symmetry::FlipVertical,
+        Symmetry::FlipHorizontal,
+        Symmetry::FlipDiagonal,
+        Symmetry::FlipAntiDiagonal,
+    ];
+    pub fn inverse(self) -> Symmetry {
+        match self {
+            Symmetry::Rotate90 => Symmetry::Rotate270,
+            Symmetry::Rotate270 => Symmetry::Rotate90,
+            s => s,
+        }
+    }
+    pub fn apply(self, bits: u64) -> u64 {
+        match self {
+            Symmetry::Identity => bits,
+            Symmetry::Rotate90 => flip_horizontal(flip_diagonal(bits)),
+            Symmetry::Rotate180 => bits.reverse_bits(),
+            Symmetry::Rotate270 => flip_vertical(flip_diagonal(bits)),
+            Symmetry::FlipVertical => flip_vertical(bits),
+            Symmetry::FlipHorizontal => flip_horizontal(bits),
+            Symmetry::FlipDiagonal => flip_diagonal(bits),
+            Symmetry::FlipAntiDiagonal => flip_anti_diagonal(bits),
+        }
+    }
+}
+
+// mirrors rows 1 and 8
+pub fn flip_vertical(bits: u64) -> u64 {
+    bits.swap_bytes()
+}
+
+// mirrors columns a and h
+pub fn flip_horizontal(bits: u64) -> u64 {
+    let k1 = 0x5555555555555555;
+    let k2 = 0x3333333333333333;
+    let k4 = 0x0f0f0f0f0f0f0f0f;
+    let bits = ((bits >> 1) & k1) | ((bits & k1) << 1);
+    let bits = ((bits >> 2) & k2) | ((bits & k2) << 2);
+    ((bits >> 4) & k4) | ((bits & k4) << 4)
+}
+
+// mirrors along a1-h8
+pub fn flip_diagonal(bits: u64) -> u64 {
+    let k1 = 0x5500550055005500;
+    let k2 = 0x3333000033330000;
+    let k4 = 0x0f0f0f0f00000000;
+    let t = k4 & (bits ^ (bits << 28));
+    let bits = bits ^ t ^ (t >> 28);
+    let t = k2 & (bits ^ (bits << 14));
+    let bits = bits ^ t ^ (t >> 14);
+    let t = k1 & (bits ^ (bits << 7));
+    bits ^ t ^ (t >> 7)
+}
+
+// mirrors along h1-a8
+pub fn flip_anti_diagonal(bits: u64) -> u64 {
+    let k1 = 0xaa00aa00aa00aa00;
+    let k2 = 0xcccc0000cccc0000;
+    let k4 = 0xf0f0f0f00f0f0f0f;
+    let t = bits ^ (bits << 36);
+    let bits = bits ^ (k4 & (t ^ (bits >> 36)));
+    let t = k2 & (bits ^ (bits << 18));
+    let bits = bits ^ t ^ (t >> 18);
+    let t = k1 & (bits ^ (bits << 9));
+    bits ^ t ^ (t >> 9)
+}
+
+impl Position {
+    pub fn transform(&self, sym: Symmetry) -> Position {
+        Position(sym.apply(self.0))
+    }
+}
+
+impl Positions {
+    pub fn transform(&self, sym: Symmetry) -> Positions {
+        Positions(sym.apply(self.0))
+    }
+}
+
+impl Move {
+    pub fn transform(&self, sym: Symmetry) -> Move {
+        match self {
+            Move::Play(pos) => Move::Play(pos.transform(sym)),
+            Move::Pass => Move::Pass,
+        }
+    }
+}
+
+impl Board {
+    pub fn transform(&self, sym: Symmetry) -> Board {
+        Board::from_raw(
+            sym.apply(self.black()),
+            sym.apply(self.white()),
+            self.turn(),
+        )
+    }
+    /// Returns the smallest of the eight symmetric boards and the symmetry
+    /// that maps `self` to it. A move `m` chosen on the canonical board is
+    /// `m.transform(sym.inverse())` on `self`.
+    pub fn canonical(&self) -> (Board, Symmetry) {
+        Symmetry::ALL
+            .iter()
+            .map(|&sym| (self.transform(sym), sym))
+            .min_by_key(|(board, _)| (board.black(), board.white()))
+            .unwrap()
+    }
+}
@@ -0,0 +1,169 @@
+use std::io::{self, Read};
+
+use crate::{game::GameRecord, BoardError, Position, SIZE};
+use thiserror::Error;
+
+const HEADER_LEN: usize = 16;
+const GAME_LEN: usize = 68;
+const PLAYER_LEN: usize = 20;
+const TOURNAMENT_LEN: usize = 26;
+
+#[derive(Debug, Error)]
+pub enum WthorError {
+    #[error("unsupported board size {0}, only 8x8 is supported")]
+    UnsupportedSize(u8),
+    #[error("solitaire databases are not supported")]
+    Solitaire,
+    #[error("invalid move byte {1} at ply {0}")]
+    InvalidMove(usize, u8),
+    #[error("illegal move {1} at ply {0}")]
+    IllegalMove(usize, Position),
+    #[error(transparent)]
+    Board(#[from] BoardError),
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct WthorHeader {
+    pub created: (u16, u8, u8),
+    pub num_games: u32,
+    pub num_records: u16,
+    pub game_year: u16,
+    pub board_size: u8,
+    pub solitaire: bool,
+    pub depth: u8,
+}
+
+impl WthorHeader {
+    fn parse(buf: &[u8; HEADER_LEN]) -> Self {
+        WthorHeader {
+            created: (buf[0] as u16 * 100 + buf[1] as u16, buf[2], buf[3]),
+            num_games: u32::from_le_bytes([buf[4], buf[5], buf[6], buf[7]]),
+            num_records: u16::from_le_bytes([buf[8], buf[9]]),
+            game_year: u16::from_le_bytes([buf[10], buf[11]]),
+            board_size: buf[12],
+            solitaire: buf[13] == 1,
+            depth: buf[14],
+        }
+    }
+}
+
+/// A game from a WTHOR database, replayed and checked through `Board::put`.
+///
+/// Scores are black's disc counts: `black_score` at the end of the game and
+/// `theoretical_score` with perfect play from `header.depth` empties.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WthorGame {
+    pub tournament: u16,
+    pub black_player: u16,
+    pub white_player: u16,
+    pub black_score: u8,
+    pub theoretical_score: u8,
+    pub record: GameRecord,
+}
+
+impl WthorGame {
+    fn parse(buf: &[u8; GAME_LEN]) -> Result<Self, WthorError> {
+        let mut record = GameRecord::new();
+        for &b in buf[8..].iter().take_while(|&&b| b != 0) {
+            let (x, y) = ((b % 10) as usize, (b / 10) as usize);
+            if !(1..=SIZE).contains(&x) || !(1..=SIZE).contains(&y) {
+                return Err(WthorError::InvalidMove(record.len(), b));
+            }
+            let pos = Position::new(x - 1, y - 1);
+            if record.board().get_legal_moves().0 & pos.0 == 0 {
+                return Err(WthorError::IllegalMove(record.len(), pos));
+            }
+            record.put(pos)?;
+        }
+        Ok(WthorGame {
+            tournament: u16::from_le_bytes([buf[0], buf[1]]),
+            black_player: u16::from_le_bytes([buf[2], buf[3]]),
+            white_player: u16::from_le_bytes([buf[4], buf[5]]),
+            black_score: buf[6],
+            theoretical_score: buf[7],
+            record,
+        })
+    }
+}
+
+/// Reads the games of a `.wtb` file one by one.
+pub struct WthorReader<R: Read> {
+    reader: R,
+    header: WthorHeader,
+    remaining: u32,
+}
+
+impl<R: Read> WthorReader<R> {
+    pub fn new(mut reader: R) -> Result<Self, WthorError> {
+        let mut buf = [0; HEADER_LEN];
+        reader.read_exact(&mut buf)?;
+        let header = WthorHeader::parse(&buf);
+        if header.board_size != 0 && header.board_size as usize != SIZE {
+            return Err(WthorError::UnsupportedSize(header.board_size));
+        }
+        if header.solitaire {
+            return Err(WthorError::Solitaire);
+        }
+        Ok(WthorReader {
+            reader,
+            header,
+            remaining: header.num_games,
+        })
+    }
+    pub fn header(&self) -> &WthorHeader {
+        &self.header
+    }
+}
+
+impl<R: Read> Iterator for WthorReader<R> {
+    type Item = Result<WthorGame, WthorError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let mut buf = [0; GAME_LEN];
+        if let Err(e) = self.reader.read_exact(&mut buf) {
+            self.remaining = 0;
+            return Some(Err(e.into()));
+        }
+        Some(WthorGame::parse(&buf))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining as usize))
+    }
+}
+
+fn read_names<R: Read>(mut reader: R, len: usize) -> Result<Vec<String>, WthorError> {
+    let mut buf = [0; HEADER_LEN];
+    reader.read_exact(&mut buf)?;
+    let header = WthorHeader::parse(&buf);
+    let mut names = Vec::with_capacity(header.num_records as usize);
+    let mut buf = vec![0; len];
+    for _ in 0..header.num_records {
+        reader.read_exact(&mut buf)?;
+        // names are NUL terminated ISO-8859-1
+        names.push(
+            buf.iter()
+                .take_while(|&&b| b != 0)
+                .map(|&b| b as char)
+                .collect::<String>()
+                .trim_end()
+                .to_string(),
+        );
+    }
+    Ok(names)
+}
+
+/// Reads a `WTHOR.JOU` file, indexed by `WthorGame::black_player`/`white_player`.
+pub fn read_players<R: Read>(reader: R) -> Result<Vec<String>, WthorError> {
+    read_names(reader, PLAYER_LEN)
+}
+
+/// Reads a `WTHOR.TRN` file, indexed by `WthorGame::tournament`.
+pub fn read_tournaments<R: Read>(reader: R) -> Result<Vec<String>, WthorError> {
+    read_names(reader, TOURNAMENT_LEN)
+}
@@ -0,0 +1,100 @@
+use crate::{Board, BoardError, Move, Position, Stone, SIZE};
+
+// splitmix64, so the keys are fixed at compile time and identical across runs
+const fn splitmix64(state: u64) -> (u64, u64) {
+    let state = state.wrapping_add(0x9e3779b97f4a7c15);
+    let mut z = state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    (state, z ^ (z >> 31))
+}
+
+const fn keys() -> [[u64; SIZE * SIZE]; 2] {
+    let mut keys = [[0; SIZE * SIZE]; 2];
+    let mut state = 0x4f7468656c6c6f21;
+    let mut color = 0;
+    while color < 2 {
+        let mut i = 0;
+        while i < SIZE * SIZE {
+            let (next, key) = splitmix64(state);
+            state = next;
+            keys[color][i] = key;
+            i += 1;
+        }
+        color += 1;
+    }
+    keys
+}
+
+// indexed by bit number, black first
+const KEYS: [[u64; SIZE * SIZE]; 2] = keys();
+const WHITE_TO_MOVE: u64 = splitmix64(0x5768697465546f4d).1;
+
+fn color_idx(stone: Stone) -> usize {
+    if stone == Stone::Black {
+        0
+    } else {
+        1
+    }
+}
+
+fn hash_bits(mut bits: u64, stone: Stone) -> u64 {
+    let keys = &KEYS[color_idx(stone)];
+    let mut hash = 0;
+    while bits != 0 {
+        hash ^= keys[bits.trailing_zeros() as usize];
+        bits &= bits - 1;
+    }
+    hash
+}
+
+/// Zobrist key change for `stone` playing `pos` and flipping `flipped`.
+///
+/// The side to move key is not included, xor `side_to_move_key()` when the
+/// turn changes.
+pub fn move_delta(stone: Stone, pos: Position, flipped: u64) -> u64 {
+    hash_bits(pos.0 | flipped, stone) ^ hash_bits(flipped, stone.reverse())
+}
+
+pub fn side_to_move_key() -> u64 {
+    WHITE_TO_MOVE
+}
+
+impl Board {
+    pub fn zobrist(&self) -> u64 {
+        let side = if self.turn() == Stone::White {
+            WHITE_TO_MOVE
+        } else {
+            0
+        };
+        hash_bits(self.black(), Stone::Black) ^ hash_bits(self.white(), Stone::White) ^ side
+    }
+    /// Hash shared by all eight symmetric boards.
+    pub fn canonical_zobrist(&self) -> u64 {
+        self.canonical().0.zobrist()
+    }
+    fn play_delta(&self, pos: Position) -> u64 {
+        move_delta(self.turn(), pos, self.flips(pos).0)
+    }
+    /// `Board::apply` that also updates `hash`, the zobrist hash of `self`.
+    pub fn apply_hashed(&mut self, mv: Move, hash: &mut u64) -> Result<(), BoardError> {
+        let delta = match mv {
+            Move::Play(pos) => self.play_delta(pos),
+            Move::Pass => 0,
+        };
+        self.apply(mv)?;
+        *hash ^= delta ^ WHITE_TO_MOVE;
+        Ok(())
+    }
+    /// `Board::put` that also updates `hash`, the zobrist hash of `self`.
+    pub fn put_hashed(&mut self, pos: Position, hash: &mut u64) -> Result<(), BoardError> {
+        let turn = self.turn();
+        let delta = self.play_delta(pos);
+        self.put(pos)?;
+        *hash ^= delta;
+        if self.turn() != turn {
+            *hash ^= WHITE_TO_MOVE;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+use othello::{
+    players::{
+        alphabeta::{evaluate, WIN_SCORE},
+        AlphaBetaPlayer, Player, SearchLimit,
+    },
+    Board, Move, Stone,
+};
+use rand::{rngs::SmallRng, SeedableRng};
+
+mod common;
+
+fn disc_difference(board: &Board) -> i32 {
+    let count = board.count_stone();
+    let diff = count.black as i32 - count.white as i32;
+    if board.turn() == Stone::Black {
+        diff
+    } else {
+        -diff
+    }
+}
+
+fn win_score(diff: i32) -> i32 {
+    diff.signum() * WIN_SCORE + diff
+}
+
+fn negamax(board: &Board, depth: u32) -> i32 {
+    common::negamax(board, depth, &disc_difference, win_score)
+}
+
+#[test]
+fn matches_negamax() {
+    let mut rng = SmallRng::seed_from_u64(3);
+    for game in 0..6 {
+        let board = common::random_position(&mut rng, 60 - game * 8);
+        if board.get_legal_moves().is_empty() {
+            continue;
+        }
+        for depth in 1..=4 {
+            let mut player = AlphaBetaPlayer::with_eval(SearchLimit::Depth(depth), disc_difference);
+            let (pos, score) = player.search(&board).unwrap();
+            assert_eq!(
+                score,
+                negamax(&board, depth),
+                "{} at depth {}",
+                board.to_position_string(),
+                depth
+            );
+            let mut next = board;
+            next.apply(Move::Play(pos)).unwrap();
+            assert_eq!(-negamax(&next, depth - 1), score);
+        }
+    }
+}
+
+#[test]
+fn finds_wipe_out() {
+    let board: Board = format!("XO{} X", "-".repeat(62)).parse().unwrap();
+    let mut player = AlphaBetaPlayer::new(SearchLimit::Depth(4));
+    assert_eq!(
+        player.search(&board),
+        Some(("c1".parse().unwrap(), WIN_SCORE + 64))
+    );
+}
+
+#[test]
+fn node_limit() {
+    let mut player = AlphaBetaPlayer::new(SearchLimit::Nodes(2000));
+    let board = Board::new();
+    let (pos, _) = player.search(&board).unwrap();
+    assert!(board.get_legal_moves().contains(pos));
+    assert!(player.nodes() <= 2001);
+
+    let mut tiny = AlphaBetaPlayer::new(SearchLimit::Nodes(0));
+    assert!(tiny.search(&board).is_some());
+}
+
+#[test]
+fn plays_a_game() {
+    let mut black = AlphaBetaPlayer::new(SearchLimit::Depth(3));
+    let mut white = AlphaBetaPlayer::with_eval(SearchLimit::Nodes(3000), |board: &Board| {
+        evaluate(board) + disc_difference(board)
+    });
+    let mut board = Board::new();
+    while !board.finished() {
+        let mv = if board.turn() == Stone::Black {
+            black.find_move(&board)
+        } else {
+            white.find_move(&board)
+        };
+        board.apply(mv.unwrap()).unwrap();
+    }
+    assert!(black.find_move(&board).is_err());
+}
@@ -0,0 +1,137 @@
+use othello::{Board, BoardError, Position, Positions, SizedBoard, Stone};
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+mod common;
+
+#[test]
+fn from_bitboards() {
+    let initial = Board::new();
+    let board = Board::from_bitboards(initial.black(), initial.white(), Stone::Black).unwrap();
+    assert_eq!(board, initial);
+    assert_eq!(
+        Board::from_bitboards(1, 1, Stone::Black),
+        Err(BoardError::OverlappingDiscs)
+    );
+}
+
+#[test]
+fn from_bitboards_normalizes_turn() {
+    // white a1 and black b1 only: black has no move, white can play c1
+    let board =
+        Board::from_bitboards(0x4000000000000000, 0x8000000000000000, Stone::Black).unwrap();
+    assert_eq!(board.turn(), Stone::White);
+    assert!(!board.must_pass());
+
+    // nobody can move, the turn is kept
+    let board = Board::from_bitboards(0x8000000000000000, 0, Stone::White).unwrap();
+    assert_eq!(board.turn(), Stone::White);
+    assert!(board.finished());
+}
+
+#[test]
+fn parsing_keeps_turn() {
+    // white has no move, black can play c1
+    let parsed: Board = format!("XO{} O", "-".repeat(62)).parse().unwrap();
+    assert_eq!(parsed.turn(), Stone::White);
+    assert!(parsed.must_pass());
+    let normalized = Board::from_bitboards(parsed.black(), parsed.white(), parsed.turn()).unwrap();
+    assert_eq!(normalized.turn(), Stone::Black);
+}
+
+#[test]
+fn larger_boards() {
+    assert_eq!(
+        SizedBoard::<6>::from_bitboards(1 << 40, 0, Stone::Black),
+        Err(BoardError::OutsideBoard)
+    );
+}
+
+#[test]
+fn flips_and_preview() {
+    let board = Board::new();
+    let f5: Position = "f5".parse().unwrap();
+    let flips = board.flips(f5);
+    assert_eq!(flips.to_position_list(), vec!["e5".parse().unwrap()]);
+    let after = board.preview(f5).unwrap();
+    assert_eq!(after.black(), board.black() | flips.0 | f5.0);
+    assert_eq!(after.white(), board.white() & !flips.0);
+    assert_eq!(after.turn(), Stone::White);
+    assert_eq!(board, Board::new());
+
+    let a1: Position = "a1".parse().unwrap();
+    assert_eq!(board.flips(a1), Positions(0));
+    assert_eq!(board.preview(a1), Err(BoardError::IllegalPosition(a1)));
+}
+
+#[test]
+fn position_string_round_trip() {
+    let initial = Board::new().to_position_string();
+    assert_eq!(
+        initial,
+        format!(
+            "{}OX{}XO{} X",
+            "-".repeat(27),
+            "-".repeat(6),
+            "-".repeat(27)
+        )
+    );
+    assert_eq!(initial.parse::<Board>(), Ok(Board::new()));
+
+    let mut rng = SmallRng::seed_from_u64(31);
+    for _ in 0..100 {
+        let empties = rng.gen_range(0..60);
+        let board = common::random_position(&mut rng, empties);
+        assert_eq!(board.to_position_string().parse::<Board>(), Ok(board));
+    }
+}
+
+#[test]
+fn position_string_variants() {
+    // OBF lines, with the moves and scores after ';'
+    let obf = format!(
+        "{} X; f5:+2; d3:0",
+        &Board::new().to_position_string()[..64]
+    );
+    assert_eq!(obf.parse::<Board>(), Ok(Board::new()));
+
+    // '*' and '.' squares, b/w for the turn, and whitespace between rows
+    let rows: Vec<String> = Board::new().to_position_string()[..64]
+        .replace('X', "*")
+        .replace('-', ".")
+        .as_bytes()
+        .chunks(8)
+        .map(|row| String::from_utf8(row.to_vec()).unwrap())
+        .collect();
+    let board: Board = format!("{}\nw", rows.join("\n")).parse().unwrap();
+    assert_eq!(
+        (board.black(), board.white()),
+        (Board::new().black(), Board::new().white())
+    );
+    assert_eq!(board.turn(), Stone::White);
+}
+
+#[test]
+fn position_string_errors() {
+    let squares = "-".repeat(64);
+    assert_eq!(
+        format!("{}Z{} X", "-".repeat(10), "-".repeat(53)).parse::<Board>(),
+        Err(BoardError::InvalidCharacter('Z'))
+    );
+    assert_eq!(
+        format!("{} Q", squares).parse::<Board>(),
+        Err(BoardError::InvalidCharacter('Q'))
+    );
+    assert_eq!(
+        format!("{} XO", squares).parse::<Board>(),
+        Err(BoardError::InvalidCharacter('O'))
+    );
+    assert_eq!(
+        "-".repeat(63).parse::<Board>(),
+        Err(BoardError::InvalidLength(63))
+    );
+    assert_eq!(squares.parse::<Board>(), Err(BoardError::MissingTurn));
+    assert_eq!(
+        format!("{}; X", squares).parse::<Board>(),
+        Err(BoardError::MissingTurn)
+    );
+}
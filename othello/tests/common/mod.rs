@@ -0,0 +1,61 @@
+// Fixtures shared by the integration tests. Each test crate uses only some
+// of them.
+#![allow(dead_code)]
+
+use othello::{Board, Move};
+use rand::rngs::SmallRng;
+
+pub fn empties(board: &Board) -> u32 {
+    (!(board.black() | board.white())).count_ones()
+}
+
+/// A uniformly random legal move, `Move::Pass` when the side to move has to pass.
+pub fn random_move(board: &Board, rng: &mut SmallRng) -> Move {
+    board
+        .get_legal_moves()
+        .choose(rng)
+        .map_or(Move::Pass, Move::Play)
+}
+
+/// Plays random moves from the initial position until at most `empties`
+/// squares are left or the game is over.
+pub fn random_position(rng: &mut SmallRng, empties: u32) -> Board {
+    let mut board = Board::new();
+    while self::empties(&board) > empties && !board.finished() {
+        board.apply(random_move(&board, rng)).unwrap();
+    }
+    board
+}
+
+/// Plain negamax without pruning, `depth` plies deep with passes free.
+///
+/// Finished games score `end` of the final disc differential for the side to
+/// move, positions at depth 0 score `leaf`.
+pub fn negamax(
+    board: &Board,
+    depth: u32,
+    leaf: &dyn Fn(&Board) -> i32,
+    end: fn(i32) -> i32,
+) -> i32 {
+    if let Some(outcome) = board.outcome() {
+        return end(outcome.differential_for(board.turn()));
+    }
+    let moves = board.get_legal_moves();
+    if moves.is_empty() {
+        let mut next = *board;
+        next.apply(Move::Pass).unwrap();
+        return -negamax(&next, depth, leaf, end);
+    }
+    if depth == 0 {
+        return leaf(board);
+    }
+    moves
+        .iter()
+        .map(|pos| {
+            let mut next = *board;
+            next.apply(Move::Play(pos)).unwrap();
+            -negamax(&next, depth - 1, leaf, end)
+        })
+        .max()
+        .unwrap()
+}
@@ -0,0 +1,85 @@
+use othello::{
+    endgame::Solver,
+    players::{EndgamePlayer, Player},
+    Board, Move, Stone,
+};
+use rand::{rngs::SmallRng, SeedableRng};
+
+mod common;
+use common::random_position;
+
+fn minimax(board: &Board) -> i32 {
+    // passes are free, so 60 plies always reach the end
+    common::negamax(board, 60, &|_| unreachable!(), |diff| diff)
+}
+
+#[test]
+fn matches_minimax() {
+    let mut rng = SmallRng::seed_from_u64(7);
+    let mut solver = Solver::new();
+    for _ in 0..30 {
+        let board = random_position(&mut rng, 8);
+        let expected = minimax(&board);
+        let solution = solver.solve(&board);
+        assert_eq!(solution.score, expected, "{}", board.to_position_string());
+        assert_eq!(solver.solve_wld(&board).score, expected.signum());
+        if let Some(mv) = solution.best {
+            let mut next = board;
+            next.apply(mv).unwrap();
+            assert_eq!(-minimax(&next), expected);
+        }
+    }
+}
+
+#[test]
+fn finished_and_pass() {
+    let mut solver = Solver::new();
+    let full: Board = format!("{}{} O", "X".repeat(40), "O".repeat(24))
+        .parse()
+        .unwrap();
+    let solution = solver.solve(&full);
+    assert_eq!((solution.best, solution.score), (None, -16));
+
+    // white has no move, then black takes c1 and wipes white out
+    let pass: Board = format!("XO{} O", "-".repeat(62)).parse().unwrap();
+    assert_eq!(pass.turn(), Stone::White);
+    let solution = solver.solve(&pass);
+    assert_eq!((solution.best, solution.score), (Some(Move::Pass), -64));
+    assert_eq!(solver.solve_wld(&pass).score, -1);
+}
+
+#[test]
+fn perfect_play_reaches_the_solved_score() {
+    let mut rng = SmallRng::seed_from_u64(11);
+    for _ in 0..3 {
+        let mut board = random_position(&mut rng, 14);
+        let start = board.turn();
+        let score = Solver::new().solve(&board).score;
+        let mut player = EndgamePlayer::default();
+        while !board.finished() {
+            let mv = player.find_move(&board).unwrap();
+            board.apply(mv).unwrap();
+        }
+        assert_eq!(board.outcome().unwrap().differential_for(start), score);
+    }
+}
+
+#[test]
+fn table_is_reused_between_solves() {
+    let mut rng = SmallRng::seed_from_u64(29);
+    let mut solver = Solver::new();
+    // a deeper solve first fills the table with positions that have more empties
+    solver.solve(&random_position(&mut rng, 16));
+    for _ in 0..3 {
+        let board = random_position(&mut rng, 14);
+        let first = solver.solve(&board);
+        let nodes = solver.nodes();
+        assert_eq!(solver.solve(&board), first);
+        assert!(
+            solver.nodes() * 10 < nodes,
+            "{} nodes, then {}",
+            nodes,
+            solver.nodes()
+        );
+    }
+}
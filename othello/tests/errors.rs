@@ -0,0 +1,39 @@
+use othello::{
+    notation::{parse_transcript, NotationError},
+    players::{GreedyPlayer, Player, PlayerError},
+    Board, BoardError, Move, Position,
+};
+
+#[test]
+fn board_errors() {
+    let mut board = Board::new();
+    let a1: Position = "a1".parse().unwrap();
+    assert_eq!(board.put(a1), Err(BoardError::IllegalPosition(a1)));
+    assert_eq!(board.apply(Move::Pass), Err(BoardError::IllegalPass));
+    assert_eq!("z9".parse::<Position>(), Err(BoardError::InvalidNotation));
+    assert_eq!("X".parse::<Board>(), Err(BoardError::InvalidLength(1)));
+
+    let mut finished: Board = format!("{} X", "X".repeat(64)).parse().unwrap();
+    assert_eq!(finished.apply(Move::Pass), Err(BoardError::GameOver));
+}
+
+#[test]
+fn notation_errors() {
+    assert_eq!(
+        parse_transcript("f5a1").err(),
+        Some(NotationError::IllegalMove(1, "a1".parse().unwrap()))
+    );
+    assert_eq!(
+        parse_transcript("f5d").err(),
+        Some(NotationError::UnexpectedEnd)
+    );
+}
+
+#[test]
+fn player_errors() {
+    let finished: Board = format!("{} X", "X".repeat(64)).parse().unwrap();
+    assert!(matches!(
+        GreedyPlayer::default().find_move(&finished),
+        Err(PlayerError::NotFoundLegalMove)
+    ));
+}
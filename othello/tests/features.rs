@@ -0,0 +1,60 @@
+use othello::{
+    features::{Features, SideFeatures},
+    Board,
+};
+
+#[test]
+fn initial_position() {
+    let side = SideFeatures {
+        discs: 2,
+        mobility: 4,
+        potential_mobility: 10,
+        frontier: 2,
+        stable: 0,
+        corners: 0,
+        x_squares: 0,
+        c_squares: 0,
+    };
+    let features = Board::new().features();
+    assert_eq!(
+        features,
+        Features {
+            player: side,
+            opponent: side,
+            empties: 60,
+            odd_regions: 0,
+            even_regions: 1,
+        }
+    );
+    assert_eq!(features.to_array()[..8], features.to_array()[8..16]);
+    assert_eq!(features.to_array()[16..], [60.0, 0.0, 1.0]);
+}
+
+#[test]
+fn empty_regions() {
+    // a1 alone, g8 and h8 together, d4, d5 and e6 joined diagonally
+    let mut squares = vec![b'X'; 64];
+    for &i in &[0, 62, 63, 27, 35, 44] {
+        squares[i] = b'-';
+    }
+    let squares = String::from_utf8(squares).unwrap() + " O";
+    let features = squares.parse::<Board>().unwrap().features();
+    assert_eq!(features.empties, 6);
+    assert_eq!(features.odd_regions, 2);
+    assert_eq!(features.even_regions, 1);
+}
+
+#[test]
+fn corner_squares() {
+    // black a1, b1 and b2, white h1 and g2
+    let squares = format!("XX-----O-X----O-{} X", "-".repeat(48));
+    let features = squares.parse::<Board>().unwrap().features();
+    assert_eq!(features.player.corners, 1);
+    assert_eq!(features.player.c_squares, 1);
+    assert_eq!(features.player.x_squares, 1);
+    assert_eq!(features.player.stable, 2);
+    assert_eq!(features.opponent.corners, 1);
+    assert_eq!(features.opponent.c_squares, 0);
+    assert_eq!(features.opponent.x_squares, 1);
+    assert_eq!(features.opponent.stable, 1);
+}
@@ -0,0 +1,118 @@
+use othello::{game::GameRecord, Board, Move, Stone};
+use rand::{rngs::SmallRng, SeedableRng};
+
+mod common;
+
+fn random_game(rng: &mut SmallRng) -> GameRecord {
+    let mut record = GameRecord::new();
+    while !record.board().finished() {
+        match common::random_move(record.board(), rng) {
+            Move::Play(pos) => record.put(pos).unwrap(),
+            Move::Pass => unreachable!("put records the passes"),
+        }
+    }
+    record
+}
+
+#[test]
+fn undo_and_redo_step_over_passes() {
+    let mut rng = SmallRng::seed_from_u64(5);
+    let mut passes = 0;
+    for _ in 0..200 {
+        let mut record = random_game(&mut rng);
+        passes += record
+            .moves()
+            .iter()
+            .filter(|&&mv| mv == Move::Pass)
+            .count();
+        let mut stops = vec![record.ply()];
+        while record.undo() {
+            assert!(!record.board().must_pass());
+            stops.push(record.ply());
+        }
+        assert_eq!(record.ply(), 0);
+        let expected: Vec<_> = (0..=record.len())
+            .rev()
+            .filter(|&ply| record.moves().get(ply) != Some(&Move::Pass))
+            .collect();
+        assert_eq!(stops, expected);
+        stops.reverse();
+        let mut redone = vec![record.ply()];
+        while record.redo() {
+            assert!(!record.board().must_pass());
+            redone.push(record.ply());
+        }
+        assert_eq!(redone, stops);
+        assert_eq!(record.ply(), record.len());
+    }
+    assert!(passes > 0);
+}
+
+#[test]
+fn game_over_keeps_the_mover() {
+    // black takes c1 and wipes white out
+    let board: Board = format!("XO{} X", "-".repeat(62)).parse().unwrap();
+    let c1 = "c1".parse().unwrap();
+    let mut record = GameRecord::from_board(board);
+    record.put(c1).unwrap();
+    let mut expected = board;
+    expected.put(c1).unwrap();
+    assert!(expected.finished());
+    assert_eq!(expected.turn(), Stone::Black);
+    assert_eq!(*record.board(), expected);
+    assert_eq!(record.moves(), [Move::Play(c1)]);
+
+    let mut applied = GameRecord::from_board(board);
+    applied.apply(Move::Play(c1)).unwrap();
+    assert_eq!(applied, record);
+}
+
+#[test]
+fn put_records_forced_passes() {
+    let mut rng = SmallRng::seed_from_u64(9);
+    for _ in 0..50 {
+        let mut record = GameRecord::new();
+        let mut board = Board::new();
+        while !board.finished() {
+            let pos = match common::random_move(&board, &mut rng) {
+                Move::Play(pos) => pos,
+                Move::Pass => unreachable!("Board::put passes automatically"),
+            };
+            board.put(pos).unwrap();
+            record.put(pos).unwrap();
+            assert_eq!(*record.board(), board);
+        }
+        let mut replayed = record.clone();
+        replayed.rewind();
+        assert_ne!(replayed, record);
+        replayed.jump(record.len()).unwrap();
+        assert_eq!(replayed, record);
+    }
+}
+
+#[test]
+fn jump_steps_over_passes() {
+    let mut rng = SmallRng::seed_from_u64(37);
+    let mut passes = 0;
+    for _ in 0..100 {
+        let mut record = random_game(&mut rng);
+        for ply in 0..=record.len() {
+            record.jump(ply).unwrap();
+            assert!(!record.board().must_pass());
+            if record.moves().get(ply) == Some(&Move::Pass) {
+                passes += 1;
+                assert_eq!(record.ply(), ply + 1);
+            } else {
+                assert_eq!(record.ply(), ply);
+            }
+            // undo and redo come back to the same position
+            let landed = record.ply();
+            if record.undo() {
+                record.redo();
+                assert_eq!(record.ply(), landed);
+            }
+        }
+        assert!(record.jump(record.len() + 1).is_err());
+    }
+    assert!(passes > 0);
+}
@@ -0,0 +1,232 @@
+use othello::{
+    othello_logic::{Bitboard, Geometry, Size},
+    perft::perft,
+    BoardError, Move, Position, SizedBoard, Stone,
+};
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+const DIRECTIONS: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+// the discs `player` flips by playing column `x`, row `y`, walking each
+// direction square by square
+fn flips<const N: usize>(
+    player: <Size<N> as Geometry>::Bits,
+    opponent: <Size<N> as Geometry>::Bits,
+    x: usize,
+    y: usize,
+) -> <Size<N> as Geometry>::Bits
+where
+    Size<N>: Geometry,
+{
+    let zero = <Size<N> as Geometry>::Bits::ZERO;
+    let square = |x: isize, y: isize| {
+        if (0..N as isize).contains(&x) && (0..N as isize).contains(&y) {
+            Size::<N>::square(x as usize, y as usize)
+        } else {
+            zero
+        }
+    };
+    let mut flipped = zero;
+    for &(dx, dy) in DIRECTIONS.iter() {
+        let mut line = zero;
+        let (mut cx, mut cy) = (x as isize + dx, y as isize + dy);
+        while square(cx, cy) & opponent != zero {
+            line = line | square(cx, cy);
+            cx += dx;
+            cy += dy;
+        }
+        if square(cx, cy) & player != zero {
+            flipped = flipped | line;
+        }
+    }
+    flipped
+}
+
+// plays random games and checks every position against `flips`
+fn matches_brute_force<const N: usize>(games: usize)
+where
+    Size<N>: Geometry,
+{
+    let zero = <Size<N> as Geometry>::Bits::ZERO;
+    let mut rng = SmallRng::seed_from_u64(N as u64);
+    for _ in 0..games {
+        let mut board = SizedBoard::<N>::new();
+        while !board.finished() {
+            let (player, opponent) = if board.turn() == Stone::Black {
+                (board.black(), board.white())
+            } else {
+                (board.white(), board.black())
+            };
+            let mut legal = vec![];
+            let mut expected = zero;
+            for y in 0..N {
+                for x in 0..N {
+                    let square = Size::<N>::square(x, y);
+                    if (player | opponent) & square != zero {
+                        continue;
+                    }
+                    let flipped = flips::<N>(player, opponent, x, y);
+                    assert_eq!(Size::<N>::reverse(player, opponent, square), flipped);
+                    if flipped != zero {
+                        expected = expected | square;
+                        legal.push((x, y));
+                    }
+                }
+            }
+            assert_eq!(Size::<N>::legal_move(player, opponent), expected);
+            assert_eq!(board.legal_bits(), expected);
+            // put_at passes automatically, so an unfinished game has a move
+            let (x, y) = legal[rng.gen_range(0..legal.len())];
+            board.put_at(x, y).unwrap();
+        }
+    }
+}
+
+#[test]
+fn six_by_six() {
+    matches_brute_force::<6>(300);
+}
+
+#[test]
+fn eight_by_eight() {
+    matches_brute_force::<8>(50);
+}
+
+#[test]
+fn ten_by_ten() {
+    matches_brute_force::<10>(50);
+}
+
+#[test]
+fn display_aligns_row_labels() {
+    let board = SizedBoard::<10>::new().to_string();
+    let lines: Vec<_> = board.lines().collect();
+    assert_eq!(lines.len(), 11);
+    assert!(lines[0].starts_with("   a b"), "{:?}", lines[0]);
+    assert!(lines[1].starts_with(" 1"));
+    assert!(lines[10].starts_with("10"));
+
+    let board = SizedBoard::<6>::new().to_string();
+    assert!(board.starts_with("  a b c d e f\n1"), "{:?}", board);
+}
+
+// plays random games through the `Move` API down to the final score
+fn random_games<const N: usize>(games: usize)
+where
+    Size<N>: Geometry,
+{
+    let mut rng = SmallRng::seed_from_u64(N as u64 + 1);
+    for _ in 0..games {
+        let mut board = SizedBoard::<N>::new();
+        while let Some(pos) = board.get_legal_moves().choose(&mut rng) {
+            let before = board;
+            let undo = board.make_move(Move::Play(pos));
+            board.unmake_move(undo);
+            assert_eq!(board, before);
+
+            let discs = |board: &SizedBoard<N>, stone| {
+                let count = board.count_stone();
+                if stone == Stone::Black {
+                    count.black
+                } else {
+                    count.white
+                }
+            };
+            let flips = board.flips(pos).count();
+            assert!(flips > 0);
+            let next = board.preview(pos).unwrap();
+            board.put(pos).unwrap();
+            assert_eq!(board, next);
+            assert_eq!(
+                discs(&board, before.turn()),
+                discs(&before, before.turn()) + flips + 1
+            );
+        }
+        assert!(board.finished());
+        let outcome = board.outcome().unwrap();
+        assert_eq!(outcome.black + outcome.white, N * N);
+    }
+}
+
+#[test]
+fn moves_and_outcome() {
+    random_games::<6>(100);
+    random_games::<10>(20);
+}
+
+// perft by make_move and unmake_move, passes counted like `perft` does
+fn count_paths<const N: usize>(board: &mut SizedBoard<N>, depth: usize, passed: bool) -> u64
+where
+    Size<N>: Geometry,
+{
+    if depth == 0 {
+        return 1;
+    }
+    let moves = board.get_legal_moves();
+    if moves.is_empty() {
+        if passed {
+            return 1;
+        }
+        let undo = board.make_move(Move::Pass);
+        let count = count_paths(board, depth - 1, true);
+        board.unmake_move(undo);
+        return count;
+    }
+    let mut count = 0;
+    for pos in moves {
+        let undo = board.make_move(Move::Play(pos));
+        count += count_paths(board, depth - 1, false);
+        board.unmake_move(undo);
+    }
+    count
+}
+
+#[test]
+fn perft_other_sizes() {
+    let board = SizedBoard::<6>::new();
+    assert_eq!(perft(&board, 1), 4);
+    for depth in 0..7 {
+        assert_eq!(
+            perft(&board, depth),
+            count_paths(&mut board.clone(), depth, false)
+        );
+    }
+    let board = SizedBoard::<10>::new();
+    for depth in 0..5 {
+        assert_eq!(
+            perft(&board, depth),
+            count_paths(&mut board.clone(), depth, false)
+        );
+    }
+}
+
+#[test]
+fn squares_of_other_sizes() {
+    let pos: Position<10> = "j10".parse().unwrap();
+    assert_eq!(pos, Position::from((9, 9)));
+    assert_eq!(pos.to_string(), "j10");
+    assert_eq!("c4".parse::<Position<6>>().unwrap().to_idx(), 20);
+    for bad in ["k1", "a11", "a0", "a01"] {
+        assert_eq!(
+            bad.parse::<Position<10>>(),
+            Err(BoardError::InvalidNotation)
+        );
+    }
+    assert_eq!(
+        "g1".parse::<Position<6>>(),
+        Err(BoardError::InvalidNotation)
+    );
+    assert_eq!(
+        SizedBoard::<10>::new().apply(Move::Play(pos)),
+        Err(BoardError::IllegalSquare(9, 9))
+    );
+}
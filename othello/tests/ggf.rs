@@ -0,0 +1,167 @@
+use othello::{
+    ggf::{parse_ggf, GgfError, GgfGame, MoveInfo, ResultKind, TimeControl},
+    notation::parse_transcript,
+    Board, Move, Stone,
+};
+
+const START: &str =
+    "BO[8 -------- -------- -------- ---O*--- ---*O--- -------- -------- -------- *]";
+
+// a white wipe-out as a GGS server writes it
+const SERVER: &str = "(;GM[Othello]PC[GGS/os]DT[2003.12.15_13:24:03.MST]PB[alpha]PW[beta]\
+RB[1826.11]RW[2012.50]TI[10:00//02:00]TY[8]RE[-64.00]\
+BO[8 -------- -------- -------- ---O*--- ---*O--- -------- -------- -------- *]\
+B[F5//0.01]W[F4/-2.00/1.20]B[C3/0.50/3]W[D6]B[D3/-10.00/0.02]W[D2/12.00/0.50]\
+B[E6]W[F6/30.00/0.10]B[C4//0.01]W[B4/64.00/0.03];)";
+
+fn parse(s: &str) -> Result<GgfGame, GgfError> {
+    s.parse()
+}
+
+fn game(moves: &str) -> String {
+    format!("(;GM[Othello]PB[a]PW[b]TY[8]{}{};)", START, moves)
+}
+
+#[test]
+fn server_game() {
+    let game = parse(SERVER).unwrap();
+    assert_eq!(game.place.as_deref(), Some("GGS/os"));
+    assert_eq!(game.date.as_deref(), Some("2003.12.15_13:24:03.MST"));
+    assert_eq!(
+        (game.black_player.as_str(), game.white_player.as_str()),
+        ("alpha", "beta")
+    );
+    assert_eq!(
+        (game.black_rating, game.white_rating),
+        (Some(1826.11), Some(2012.5))
+    );
+    assert_eq!(
+        game.time_control,
+        Some(TimeControl {
+            main: 600,
+            extension: 0,
+            increment: 120,
+        })
+    );
+    let result = game.result.unwrap();
+    assert_eq!((result.score, result.kind), (-64.0, ResultKind::Normal));
+
+    let expected = parse_transcript("f5f4c3d6d3d2e6f6c4b4").unwrap();
+    assert_eq!(game.record.moves(), expected.moves());
+    assert!(game.record.board().finished());
+    assert_eq!(game.record.board().count_stone().black, 0);
+    assert_eq!(game.move_info.len(), 10);
+    assert_eq!(
+        game.move_info[0],
+        MoveInfo {
+            eval: None,
+            time: Some(0.01),
+        }
+    );
+    assert_eq!(
+        game.move_info[1],
+        MoveInfo {
+            eval: Some(-2.0),
+            time: Some(1.2),
+        }
+    );
+    assert_eq!(game.move_info[3], MoveInfo::default());
+}
+
+#[test]
+fn round_trip() {
+    let game = parse(SERVER).unwrap();
+    let written = game.to_string();
+    assert!(written.contains("TI[10:00//02:00]"), "{}", written);
+    assert!(written.contains("B[F5//0.01]W[F4/-2/1.2]"), "{}", written);
+    let reparsed = parse(&written).unwrap();
+    assert_eq!(reparsed.to_string(), written);
+    assert_eq!(reparsed.record.moves(), game.record.moves());
+    assert_eq!(reparsed.move_info, game.move_info);
+    assert_eq!(reparsed.time_control, game.time_control);
+    assert_eq!(reparsed.result, game.result);
+    assert_eq!(reparsed.black_rating, game.black_rating);
+}
+
+#[test]
+fn passes() {
+    // white has no move after a1, so it passes explicitly or implicitly
+    let board = "BO[8 XO------ -------- -------- -------- -------- -------- -------- -------- O]";
+    let board = board.replace('X', "*");
+    let explicit = parse(
+        &game("")
+            .replace(START, &board)
+            .replace(";)", "W[PA]B[C1];)"),
+    )
+    .unwrap();
+    let implicit = parse(&game("").replace(START, &board).replace(";)", "B[C1];)")).unwrap();
+    assert_eq!(
+        explicit.record.moves(),
+        [Move::Pass, Move::Play("c1".parse().unwrap())]
+    );
+    assert_eq!(implicit.record.moves(), explicit.record.moves());
+    assert_eq!(implicit.move_info.len(), 2);
+    assert_eq!(explicit.record.initial_board().turn(), Stone::White);
+    assert!(explicit.to_string().contains("W[PA]B[C1]"));
+}
+
+#[test]
+fn several_games() {
+    let file = format!("{}\n{}\n", SERVER, game("B[f5]"));
+    let games = parse_ggf(&file).unwrap();
+    assert_eq!(games.len(), 2);
+    assert_eq!(games[1].record.moves(), [Move::Play("f5".parse().unwrap())]);
+    assert_eq!(*games[1].record.initial_board(), Board::new());
+}
+
+#[test]
+fn unsupported_size() {
+    assert_eq!(
+        parse(&game("").replace("TY[8]", "TY[10]")).err(),
+        Some(GgfError::UnsupportedSize(10))
+    );
+    assert_eq!(
+        parse(&game("").replace("BO[8 ", "BO[10 ")).err(),
+        Some(GgfError::UnsupportedSize(10))
+    );
+}
+
+#[test]
+fn unsupported_variant() {
+    assert_eq!(
+        parse(&game("").replace("TY[8]", "TY[s8r18]")).err(),
+        Some(GgfError::UnsupportedVariant("s8r18".to_string()))
+    );
+    assert_eq!(
+        parse(&game("").replace("TY[8]", "TY[8a]")).err(),
+        Some(GgfError::UnsupportedVariant("8a".to_string()))
+    );
+    // random starts are ordinary games from their BO position
+    assert!(parse(&game("B[F5]").replace("TY[8]", "TY[8r20]")).is_ok());
+}
+
+#[test]
+fn out_of_turn() {
+    assert_eq!(parse(&game("W[F5]")).err(), Some(GgfError::OutOfTurn(0)));
+    assert_eq!(
+        parse(&game("B[F5]B[F6]")).err(),
+        Some(GgfError::OutOfTurn(1))
+    );
+}
+
+#[test]
+fn illegal_pass() {
+    assert_eq!(parse(&game("B[PA]")).err(), Some(GgfError::IllegalPass(0)));
+    assert_eq!(
+        parse(&game("B[F5]W[pa]")).err(),
+        Some(GgfError::IllegalPass(1))
+    );
+}
+
+#[test]
+fn illegal_move() {
+    assert_eq!(
+        parse(&game("B[A1]")).err(),
+        Some(GgfError::IllegalMove(0, "a1".parse().unwrap()))
+    );
+}
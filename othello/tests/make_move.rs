@@ -0,0 +1,37 @@
+use othello::{Board, Move, Position};
+use rand::{rngs::SmallRng, SeedableRng};
+
+mod common;
+
+#[test]
+fn unmake_restores_position() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    for _ in 0..100 {
+        let mut board = Board::new();
+        let mut history = vec![];
+        while !board.finished() {
+            let mv = common::random_move(&board, &mut rng);
+            let mut expected = board;
+            expected.apply(mv).unwrap();
+            let before = board;
+            let undo = board.make_move(mv);
+            assert_eq!(board, expected);
+            history.push((before, undo));
+        }
+        while let Some((before, undo)) = history.pop() {
+            board.unmake_move(undo);
+            assert_eq!(board, before);
+        }
+        assert_eq!(board, Board::new());
+    }
+}
+
+#[test]
+fn undo_record() {
+    let mut board = Board::new();
+    let f5: Position = "f5".parse().unwrap();
+    let undo = board.make_move(Move::Play(f5));
+    assert_eq!(undo.mv, Move::Play(f5));
+    assert_eq!(undo.flipped, "e5".parse::<Position>().unwrap().0);
+    assert_eq!(undo.turn, othello::Stone::Black);
+}
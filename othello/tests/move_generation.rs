@@ -0,0 +1,82 @@
+use othello::othello_logic::{backends, legal_move, put, reverse};
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+// every backend, and the dispatching functions, against the reference
+fn check(player: u64, opponent: u64) {
+    let moves = backends::legal_move_reference(player, opponent);
+    assert_eq!(legal_move(player, opponent), moves);
+    for (name, legal_move_fn, reverse_fn) in backends::available() {
+        assert_eq!(
+            legal_move_fn(player, opponent),
+            moves,
+            "{} {:016x} {:016x}",
+            name,
+            player,
+            opponent
+        );
+        let mut empty = !(player | opponent);
+        while empty != 0 {
+            let pos = empty & empty.wrapping_neg();
+            empty ^= pos;
+            let expected = backends::reverse_reference(player, opponent, pos);
+            assert_eq!(reverse(player, opponent, pos), expected);
+            assert_eq!(
+                reverse_fn(player, opponent, pos),
+                expected,
+                "{} {:016x} {:016x} {:016x}",
+                name,
+                player,
+                opponent,
+                pos
+            );
+        }
+    }
+}
+
+#[test]
+fn backends_available() {
+    let names: Vec<_> = backends::available().map(|(name, _, _)| name).collect();
+    assert_eq!(names[..2], ["reference", "portable"]);
+    #[cfg(target_arch = "x86_64")]
+    assert!(names.contains(&"sse2"));
+}
+
+#[test]
+fn random_positions() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    for _ in 0..20000 {
+        let occupied: u64 = rng.gen::<u64>() | rng.gen::<u64>();
+        let player = occupied & rng.gen::<u64>();
+        check(player, occupied & !player);
+    }
+}
+
+#[test]
+fn random_games() {
+    let mut rng = SmallRng::seed_from_u64(1);
+    for _ in 0..200 {
+        let (mut player, mut opponent) = (0x0000000810000000, 0x0000001008000000);
+        let mut passed = false;
+        loop {
+            check(player, opponent);
+            let moves = legal_move(player, opponent);
+            if moves == 0 {
+                if passed {
+                    break;
+                }
+                passed = true;
+            } else {
+                passed = false;
+                let n = rng.gen_range(0..moves.count_ones());
+                let mut rest = moves;
+                for _ in 0..n {
+                    rest &= rest - 1;
+                }
+                let (p, o) = put(player, opponent, rest & rest.wrapping_neg());
+                player = p;
+                opponent = o;
+            }
+            std::mem::swap(&mut player, &mut opponent);
+        }
+    }
+}
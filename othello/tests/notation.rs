@@ -0,0 +1,51 @@
+use othello::{
+    notation::{parse_transcript, to_transcript, NotationError},
+    BoardError, Move,
+};
+
+// a full random game where white has to pass before black's last move
+const GAME: &str = "d3c3f5e3e2f3c4f6b3g5g7c2d1c6f2a3d6c5f4g1g2d7b2e1c7h2f1b7c8b5h3e6\
+a4b1e8e7f7g3b6c1a7a8a6d8g4f8a1g6d2h8a2b8h6h7h1a5b4h4h5";
+
+#[test]
+fn round_trip() {
+    let transcript = format!("{}g8", GAME);
+    let record = parse_transcript(&transcript).unwrap();
+    assert!(record.board().finished());
+    assert_eq!(record.len(), 61);
+    assert_eq!(to_transcript(&record), transcript);
+    assert_eq!(parse_transcript(&to_transcript(&record)).unwrap(), record);
+}
+
+#[test]
+fn explicit_passes() {
+    let implicit = parse_transcript(&format!("{}g8", GAME)).unwrap();
+    assert_eq!(implicit.moves()[59], Move::Pass);
+    for pass in ["pa", "PA", "ps", "--"] {
+        let explicit = parse_transcript(&format!("{}{}g8", GAME, pass)).unwrap();
+        assert_eq!(explicit, implicit, "{}", pass);
+    }
+    // separators are ignored
+    let spaced = parse_transcript(&format!("{} pa, g8", GAME)).unwrap();
+    assert_eq!(spaced, implicit);
+}
+
+#[test]
+fn rejects_bad_tokens() {
+    assert_eq!(
+        parse_transcript("f5a1").err(),
+        Some(NotationError::IllegalMove(1, "a1".parse().unwrap()))
+    );
+    assert_eq!(
+        parse_transcript("f5pa").err(),
+        Some(NotationError::IllegalPass(1))
+    );
+    assert_eq!(
+        parse_transcript("f5z9").err(),
+        Some(NotationError::Board(BoardError::InvalidNotation))
+    );
+    assert_eq!(
+        parse_transcript("f5d").err(),
+        Some(NotationError::UnexpectedEnd)
+    );
+}
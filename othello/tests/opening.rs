@@ -0,0 +1,92 @@
+use othello::{
+    game::GameRecord,
+    notation::parse_transcript,
+    opening::{identify, OPENINGS},
+    symmetry::Symmetry,
+    Board, Move,
+};
+
+fn opening(transcript: &str) -> Option<(&'static str, Option<usize>)> {
+    parse_transcript(transcript)
+        .unwrap()
+        .opening()
+        .map(|m| (m.name, m.left_theory))
+}
+
+#[test]
+fn catalogue_lines_are_legal() {
+    for opening in OPENINGS {
+        let record = GameRecord::replay(Board::new(), opening.moves).unwrap();
+        let found = record.opening().unwrap();
+        assert_eq!(found.name, opening.name);
+        assert_eq!(found.len, record.len());
+        assert_eq!(found.left_theory, None);
+    }
+}
+
+#[test]
+fn longest_line_wins() {
+    assert_eq!(opening("f5d6c5f4e3"), Some(("Cow", None)));
+    assert_eq!(opening("f5d6c5f4e3c6d3"), Some(("Cow", None)));
+    assert_eq!(opening("f5d6c5f4e3c6d3f6e6d7"), Some(("Rose", None)));
+    assert_eq!(opening("f5d6c5f4e3c6d3f6e6d7g5"), Some(("Rose", Some(10))));
+    assert_eq!(
+        opening("f5d6c5f4e3c6d3f6e6d7g3c4"),
+        Some(("Rose-Birdie", None))
+    );
+    assert_eq!(opening("f5d6c5f4d3"), Some(("Horse", None)));
+    assert_eq!(opening("f5d6c5f4f3"), Some(("Perpendicular", Some(4))));
+    assert_eq!(opening("f5f6e6f4g5c6"), Some(("Heath", Some(5))));
+    assert_eq!(opening("f5"), None);
+}
+
+#[test]
+fn symmetric_first_moves() {
+    let tiger: Vec<Move> = OPENINGS
+        .iter()
+        .find(|o| o.name == "Tiger")
+        .unwrap()
+        .moves
+        .iter()
+        .map(|&pos| Move::Play(pos))
+        .collect();
+    let mut first_moves = vec![];
+    for &sym in Symmetry::ALL.iter() {
+        if Board::new().transform(sym) != Board::new() {
+            continue;
+        }
+        let moves: Vec<Move> = tiger.iter().map(|mv| mv.transform(sym)).collect();
+        first_moves.push(moves[0].to_string());
+        assert_eq!(identify(&moves).unwrap().name, "Tiger");
+    }
+    first_moves.sort();
+    assert_eq!(first_moves, ["c4", "d3", "e6", "f5"]);
+}
+
+#[test]
+fn transpositions() {
+    // Heath with black's first two moves swapped
+    assert_eq!(opening("e6f6f5f4g5"), Some(("Heath", None)));
+    // even when the moves in between are out of theory
+    assert_eq!(
+        opening("f5f6e6f4e3c5g4g3g5f3c4"),
+        Some(("Melnikov", Some(6)))
+    );
+    // and from another first move, d3 for f5
+    let heath: Vec<Move> = parse_transcript("e6f6f5f4g5")
+        .unwrap()
+        .moves()
+        .iter()
+        .map(|mv| mv.transform(Symmetry::FlipAntiDiagonal))
+        .collect();
+    assert_eq!(identify(&heath).map(|m| m.name), Some("Heath"));
+}
+
+#[test]
+fn catalogue_names_are_unique() {
+    let mut names: Vec<_> = OPENINGS.iter().map(|o| o.name).collect();
+    names.sort_unstable();
+    names.dedup();
+    assert_eq!(names.len(), OPENINGS.len());
+    assert!(OPENINGS.len() >= 50);
+}
@@ -0,0 +1,59 @@
+use othello::{
+    outcome::{GameEnd, GameOutcome},
+    Board, Stone,
+};
+
+fn board(squares: &str) -> Board {
+    squares.parse().unwrap()
+}
+
+#[test]
+fn unfinished_game() {
+    assert_eq!(Board::new().outcome(), None);
+}
+
+#[test]
+fn full_board() {
+    let squares = format!("{}{} X", "X".repeat(33), "O".repeat(31));
+    let outcome = board(&squares).outcome().unwrap();
+    assert_eq!(
+        outcome,
+        GameOutcome {
+            winner: Some(Stone::Black),
+            black: 33,
+            white: 31,
+            end: GameEnd::FullBoard,
+        }
+    );
+    assert_eq!(outcome.differential(), 2);
+    assert_eq!(outcome.differential_for(Stone::White), -2);
+    assert_eq!(outcome.result_for(Stone::White), -1);
+}
+
+#[test]
+fn empties_go_to_winner() {
+    // white wiped out black with 50 empty squares left
+    let squares = format!("{}{} X", "O".repeat(14), "-".repeat(50));
+    let outcome = board(&squares).outcome().unwrap();
+    assert_eq!(outcome.winner, Some(Stone::White));
+    assert_eq!((outcome.black, outcome.white), (0, 64));
+    assert_eq!(outcome.end, GameEnd::MutualPass);
+    assert_eq!(outcome.result_for(Stone::White), 1);
+}
+
+#[test]
+fn draw_splits_empties() {
+    // the two blocks of discs can't reach each other
+    let squares = format!(
+        "{}{}{}{} X",
+        "X".repeat(8),
+        "X".repeat(8),
+        "-".repeat(32),
+        "O".repeat(16)
+    );
+    let outcome = board(&squares).outcome().unwrap();
+    assert_eq!(outcome.winner, None);
+    assert_eq!((outcome.black, outcome.white), (32, 32));
+    assert_eq!(outcome.differential(), 0);
+    assert_eq!(outcome.result_for(Stone::Black), 0);
+}
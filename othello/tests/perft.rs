@@ -0,0 +1,32 @@
+use othello::{
+    perft::{perft, perft_symmetric, PERFT_COUNTS},
+    symmetry::Symmetry,
+    Board,
+};
+
+#[test]
+fn initial_position() {
+    let board = Board::new();
+    for (depth, &count) in PERFT_COUNTS.iter().enumerate().take(10) {
+        assert_eq!(perft(&board, depth), count, "depth {}", depth);
+    }
+}
+
+#[test]
+fn symmetric_merge() {
+    let board = Board::new();
+    for (depth, &count) in PERFT_COUNTS.iter().enumerate().take(10) {
+        assert_eq!(perft_symmetric(&board, depth), count, "depth {}", depth);
+    }
+}
+
+#[test]
+fn symmetric_positions() {
+    let board: Board = "---------------------------OX------XXX-------------------------- O"
+        .parse()
+        .unwrap();
+    let count = perft(&board, 7);
+    for &sym in Symmetry::ALL.iter() {
+        assert_eq!(perft(&board.transform(sym), 7), count);
+    }
+}
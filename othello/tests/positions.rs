@@ -0,0 +1,62 @@
+use othello::{utils, BoardError, Position, Positions, SIZE, UPPER_LEFT};
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+// the square by square scan the iterator replaces
+fn scan(bits: u64) -> Vec<Position> {
+    (0..SIZE * SIZE)
+        .map(|i| UPPER_LEFT >> i)
+        .filter(|pos| bits & pos != 0)
+        .map(Position)
+        .collect()
+}
+
+#[test]
+fn iteration_order() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    for _ in 0..1000 {
+        let bits: u64 = rng.gen::<u64>() & rng.gen::<u64>();
+        let positions = Positions(bits);
+        let expected = scan(bits);
+        assert_eq!(positions.iter().collect::<Vec<_>>(), expected);
+        assert_eq!(positions.iter().len(), expected.len());
+        let mut reversed: Vec<_> = positions.iter().rev().collect();
+        reversed.reverse();
+        assert_eq!(reversed, expected);
+        for (n, &pos) in expected.iter().enumerate() {
+            assert_eq!(positions.nth(n), Some(pos));
+        }
+        assert_eq!(positions.nth(expected.len()), None);
+    }
+}
+
+#[test]
+fn to_idx() {
+    for i in 0..SIZE * SIZE {
+        assert_eq!(Position::<SIZE>(UPPER_LEFT >> i).to_idx(), i);
+    }
+    assert_eq!("d3".parse::<Position>().unwrap().to_idx(), 19);
+}
+
+#[test]
+fn input_parse() {
+    assert_eq!(utils::input_parse("a1"), Ok((0, 0)));
+    assert_eq!(utils::input_parse("F5"), Ok((5, 4)));
+    assert_eq!(utils::input_parse("h8"), Ok((7, 7)));
+    for bad in ["a0", "i1", "a9", "f", "f55"] {
+        assert_eq!(utils::input_parse(bad), Err(BoardError::InvalidNotation));
+    }
+}
+
+#[test]
+fn choose() {
+    let mut rng = SmallRng::seed_from_u64(1);
+    assert_eq!(Positions::<SIZE>(0).choose(&mut rng), None);
+    let positions: Positions = Positions(0x0000102004080000);
+    let mut seen = Positions(0);
+    for _ in 0..100 {
+        let pos = positions.choose(&mut rng).unwrap();
+        assert!(positions.contains(pos));
+        seen.0 |= pos.0;
+    }
+    assert_eq!(seen, positions);
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "serde")]
+
+use othello::{
+    game::GameRecord, notation::parse_transcript, Board, Move, Position, Positions, Stone,
+};
+
+#[test]
+fn squares_and_moves() {
+    let d3: Position = "d3".parse().unwrap();
+    assert_eq!(serde_json::to_string(&d3).unwrap(), r#""d3""#);
+    assert_eq!(serde_json::from_str::<Position>(r#""D3""#).unwrap(), d3);
+    assert_eq!(serde_json::to_string(&Move::Pass).unwrap(), r#""pa""#);
+    assert_eq!(
+        serde_json::from_str::<Move>(r#""d3""#).unwrap(),
+        Move::Play(d3)
+    );
+    assert_eq!(serde_json::to_string(&Stone::White).unwrap(), r#""white""#);
+    assert!(serde_json::from_str::<Position>(r#""i9""#).is_err());
+}
+
+#[test]
+fn board_as_position_string() {
+    let board = Board::new();
+    let json = serde_json::to_string(&board).unwrap();
+    assert_eq!(json, format!("{:?}", board.to_position_string()));
+    assert_eq!(serde_json::from_str::<Board>(&json).unwrap(), board);
+
+    // white has to pass but stays to move
+    let json = format!("\"XO{} O\"", "-".repeat(62));
+    let board = serde_json::from_str::<Board>(&json).unwrap();
+    assert_eq!(board.turn(), Stone::White);
+    assert!(board.must_pass());
+}
+
+#[test]
+fn positions_as_square_list() {
+    let moves = Board::new().get_legal_moves();
+    let json = serde_json::to_string(&moves).unwrap();
+    assert_eq!(json, r#"["d3","c4","f5","e6"]"#);
+    assert_eq!(serde_json::from_str::<Positions>(&json).unwrap().0, moves.0);
+}
+
+#[test]
+fn game_record_round_trip() {
+    let mut record = parse_transcript("f5d6c3d3c4").unwrap();
+    record.undo();
+    let json = serde_json::to_string(&record).unwrap();
+    let back: GameRecord = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.moves(), record.moves());
+    assert_eq!(back.ply(), record.ply());
+    assert_eq!(back, record);
+    assert_eq!(back.board(), record.board());
+
+    // illegal games are rejected
+    let json = json.replace(r#""f5""#, r#""a1""#);
+    assert!(serde_json::from_str::<GameRecord>(&json).is_err());
+}
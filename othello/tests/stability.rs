@@ -0,0 +1,47 @@
+use othello::{stability::stable_discs, Board, Stone};
+use rand::{rngs::SmallRng, SeedableRng};
+
+mod common;
+use common::random_move;
+
+#[test]
+fn corners_and_edges() {
+    let squares = format!("XXXO----X-------O-------{} O", "-".repeat(40));
+    let board: Board = squares.parse().unwrap();
+    // a1-c1 hang on the corner, a2 too, d1 and a3 can still be flipped
+    assert_eq!(
+        board.stable_discs(Stone::Black).to_position_list(),
+        ["a1", "b1", "c1", "a2"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect::<Vec<_>>()
+    );
+    assert!(board.stable_discs(Stone::White).is_empty());
+    assert_eq!(stable_discs(0, 0), 0);
+    assert_eq!(stable_discs(!0, 0), !0);
+}
+
+#[test]
+fn stable_discs_never_flip() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    for _ in 0..200 {
+        let mut board = Board::new();
+        let plies = rand::Rng::gen_range(&mut rng, 0..60);
+        for _ in 0..plies {
+            if board.finished() {
+                break;
+            }
+            board.apply(random_move(&board, &mut rng)).unwrap();
+        }
+        let black = board.stable_discs(Stone::Black).0;
+        let white = board.stable_discs(Stone::White).0;
+        for _ in 0..20 {
+            let mut rest = board;
+            while !rest.finished() {
+                rest.apply(random_move(&rest, &mut rng)).unwrap();
+                assert_eq!(rest.black() & black, black);
+                assert_eq!(rest.white() & white, white);
+            }
+        }
+    }
+}
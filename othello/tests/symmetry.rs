@@ -0,0 +1,77 @@
+use othello::{symmetry::Symmetry, Move, Position};
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+mod common;
+
+fn square(s: &str) -> Position {
+    s.parse().unwrap()
+}
+
+#[test]
+fn corners() {
+    // where a1, h1, a8 and h8 go, with row 1 at the top
+    let expected = [
+        (Symmetry::Identity, ["a1", "h1", "a8", "h8"]),
+        (Symmetry::Rotate90, ["h1", "h8", "a1", "a8"]),
+        (Symmetry::Rotate180, ["h8", "a8", "h1", "a1"]),
+        (Symmetry::Rotate270, ["a8", "a1", "h8", "h1"]),
+        (Symmetry::FlipVertical, ["a8", "h8", "a1", "h1"]),
+        (Symmetry::FlipHorizontal, ["h1", "a1", "h8", "a8"]),
+        (Symmetry::FlipDiagonal, ["a1", "a8", "h1", "h8"]),
+        (Symmetry::FlipAntiDiagonal, ["h8", "h1", "a8", "a1"]),
+    ];
+    for (sym, corners) in expected.iter() {
+        for (from, to) in ["a1", "h1", "a8", "h8"].iter().zip(corners.iter()) {
+            assert_eq!(
+                square(from).transform(*sym),
+                square(to),
+                "{:?} {}",
+                sym,
+                from
+            );
+        }
+    }
+    // clockwise moves along the top edge to the right edge
+    assert_eq!(square("b1").transform(Symmetry::Rotate90), square("h2"));
+    assert_eq!(square("b1").transform(Symmetry::Rotate270), square("a7"));
+}
+
+#[test]
+fn inverse_undoes() {
+    let mut rng = SmallRng::seed_from_u64(19);
+    for _ in 0..1000 {
+        let bits: u64 = rng.gen();
+        for sym in Symmetry::ALL {
+            let moved = sym.apply(bits);
+            assert_eq!(moved.count_ones(), bits.count_ones());
+            assert_eq!(sym.inverse().apply(moved), bits, "{:?}", sym);
+            assert_eq!(sym.apply(sym.inverse().apply(bits)), bits, "{:?}", sym);
+        }
+    }
+}
+
+#[test]
+fn canonical_moves_map_back() {
+    let mut rng = SmallRng::seed_from_u64(23);
+    for _ in 0..200 {
+        let empties = rng.gen_range(0..60);
+        let board = common::random_position(&mut rng, empties);
+        let (canonical, sym) = board.canonical();
+        assert_eq!(canonical, board.transform(sym));
+        for other in Symmetry::ALL {
+            assert_eq!(board.transform(other).canonical().0, canonical);
+        }
+        for pos in canonical.get_legal_moves() {
+            let mv = Move::Play(pos).transform(sym.inverse());
+            let mut played = board;
+            played.apply(mv).unwrap();
+            let mut expected = canonical;
+            expected.apply(Move::Play(pos)).unwrap();
+            assert_eq!(played.transform(sym), expected);
+        }
+        assert_eq!(
+            canonical.get_legal_moves().transform(sym.inverse()),
+            board.get_legal_moves()
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use othello::{
+    notation::parse_transcript,
+    wthor::{read_players, WthorError, WthorGame, WthorReader},
+    Move,
+};
+
+const MOVES: &str = "f5f4c3d6d3d2e6f6c4b4";
+
+fn header(num_games: u32, num_records: u16) -> Vec<u8> {
+    let mut buf = vec![20, 24, 3, 15];
+    buf.extend_from_slice(&num_games.to_le_bytes());
+    buf.extend_from_slice(&num_records.to_le_bytes());
+    buf.extend_from_slice(&2023u16.to_le_bytes());
+    // board size, solitaire, depth and a reserved byte
+    buf.extend_from_slice(&[8, 0, 22, 0]);
+    buf
+}
+
+fn game(moves: &[u8]) -> Vec<u8> {
+    let mut buf = vec![7, 0, 1, 0, 2, 0, 0, 0];
+    buf.extend_from_slice(moves);
+    buf.resize(68, 0);
+    buf
+}
+
+// squares are written as 10 * row + column, both from 1
+fn move_bytes(transcript: &str) -> Vec<u8> {
+    parse_transcript(transcript)
+        .unwrap()
+        .moves()
+        .iter()
+        .filter_map(|mv| match mv {
+            Move::Play(pos) => Some(pos.to_idx() as u8),
+            Move::Pass => None,
+        })
+        .map(|i| 10 * (i / 8 + 1) + i % 8 + 1)
+        .collect()
+}
+
+fn read_one(moves: &[u8]) -> Result<WthorGame, WthorError> {
+    let mut file = header(1, 0);
+    file.extend(game(moves));
+    WthorReader::new(&file[..])?.next().unwrap()
+}
+
+#[test]
+fn reads_games() {
+    let mut file = header(1, 0);
+    file.extend(game(&move_bytes(MOVES)));
+    let mut reader = WthorReader::new(&file[..]).unwrap();
+    let header = *reader.header();
+    assert_eq!(header.created, (2024, 3, 15));
+    assert_eq!((header.num_games, header.game_year), (1, 2023));
+    assert_eq!(
+        (header.board_size, header.solitaire, header.depth),
+        (8, false, 22)
+    );
+
+    let game = reader.next().unwrap().unwrap();
+    assert_eq!(
+        (game.tournament, game.black_player, game.white_player),
+        (7, 1, 2)
+    );
+    assert_eq!((game.black_score, game.theoretical_score), (0, 0));
+    assert_eq!(
+        game.record.moves(),
+        parse_transcript(MOVES).unwrap().moves()
+    );
+    assert_eq!(game.record.board().count_stone().black, 0);
+    assert!(reader.next().is_none());
+}
+
+#[test]
+fn truncated_file() {
+    let mut file = header(2, 0);
+    file.extend(game(&move_bytes(MOVES)));
+    let games: Vec<_> = WthorReader::new(&file[..]).unwrap().collect();
+    assert_eq!(games.len(), 2);
+    assert!(matches!(games[1], Err(WthorError::Io(_))));
+}
+
+#[test]
+fn invalid_move() {
+    let mut moves = move_bytes("f5d6");
+    moves.push(59);
+    assert!(matches!(
+        read_one(&moves),
+        Err(WthorError::InvalidMove(2, 59))
+    ));
+    assert!(matches!(
+        read_one(&[90]),
+        Err(WthorError::InvalidMove(0, 90))
+    ));
+}
+
+#[test]
+fn illegal_move() {
+    let a1 = "a1".parse().unwrap();
+    assert!(matches!(read_one(&[11]), Err(WthorError::IllegalMove(0, pos)) if pos == a1));
+}
+
+#[test]
+fn unsupported_header() {
+    let mut file = header(0, 0);
+    file[12] = 10;
+    assert!(matches!(
+        WthorReader::new(&file[..]),
+        Err(WthorError::UnsupportedSize(10))
+    ));
+    // size 0 is what old files write for 8x8
+    file[12] = 0;
+    assert!(WthorReader::new(&file[..]).is_ok());
+    file[13] = 1;
+    assert!(matches!(
+        WthorReader::new(&file[..]),
+        Err(WthorError::Solitaire)
+    ));
+}
+
+#[test]
+fn players() {
+    let mut file = header(0, 2);
+    for name in ["Tastet Marc", "Shaman Brian"] {
+        let mut record = name.as_bytes().to_vec();
+        record.resize(20, 0);
+        file.extend(record);
+    }
+    assert_eq!(
+        read_players(&file[..]).unwrap(),
+        ["Tastet Marc", "Shaman Brian"]
+    );
+    assert!(matches!(read_players(&file[..40]), Err(WthorError::Io(_))));
+}
@@ -0,0 +1,47 @@
+use othello::{symmetry::Symmetry, Board, Move};
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+mod common;
+
+#[test]
+fn incremental_hash_matches() {
+    let mut rng = SmallRng::seed_from_u64(13);
+    let mut passes = 0;
+    for _ in 0..100 {
+        // apply_hashed plays the passes explicitly
+        let mut board = Board::new();
+        let mut hash = board.zobrist();
+        while !board.finished() {
+            let mv = common::random_move(&board, &mut rng);
+            passes += (mv == Move::Pass) as u32;
+            board.apply_hashed(mv, &mut hash).unwrap();
+            assert_eq!(hash, board.zobrist(), "{}", board.to_position_string());
+        }
+
+        // put_hashed passes automatically
+        let mut board = Board::new();
+        let mut hash = board.zobrist();
+        while !board.finished() {
+            let pos = match common::random_move(&board, &mut rng) {
+                Move::Play(pos) => pos,
+                Move::Pass => unreachable!("put passes automatically"),
+            };
+            board.put_hashed(pos, &mut hash).unwrap();
+            assert_eq!(hash, board.zobrist(), "{}", board.to_position_string());
+        }
+    }
+    assert!(passes > 0);
+}
+
+#[test]
+fn canonical_hash_is_symmetric() {
+    let mut rng = SmallRng::seed_from_u64(17);
+    for _ in 0..100 {
+        let empties = rng.gen_range(0..60);
+        let board = common::random_position(&mut rng, empties);
+        let hash = board.canonical_zobrist();
+        for sym in Symmetry::ALL {
+            assert_eq!(board.transform(sym).canonical_zobrist(), hash);
+        }
+    }
+}